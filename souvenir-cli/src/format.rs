@@ -0,0 +1,153 @@
+use base64::Engine;
+use base64::engine::general_purpose::{STANDARD, STANDARD_NO_PAD};
+use clap::ValueEnum;
+use souvenir::{Error, Id};
+use std::fmt::{Display, Formatter};
+use uuid::Uuid;
+
+/// A representation of an [`Id`] accepted and produced by the tool.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// The prefixed string representation, such as `user_02v58c5a3fy30k560qrtg4`
+    String,
+
+    /// The 16 bytes as 32 lowercase hexadecimal digits
+    Hex,
+
+    /// The 16 bytes as a hyphenated UUID
+    Uuid,
+
+    /// The 16 bytes encoded with standard padded base64
+    Base64,
+}
+
+/// An error encountered while decoding an [`Id`] from a [`Format`].
+#[derive(Debug)]
+pub enum DecodeError {
+    /// The value is not valid in the requested format.
+    Format(Format),
+
+    /// The value is well-formed but does not hold a valid identifier.
+    Id(Error),
+}
+
+impl Format {
+    /// Encode the provided [`Id`] in this format.
+    pub fn encode(self, id: Id) -> String {
+        match self {
+            Self::String => id.to_string(),
+            Self::Hex => format!("{:032x}", id.to_u128()),
+            Self::Uuid => id.to_uuid().hyphenated().to_string(),
+            Self::Base64 => STANDARD.encode(id.as_bytes()),
+        }
+    }
+
+    /// Decode an [`Id`] from a value in this format.
+    pub fn decode(self, value: &str) -> Result<Id, DecodeError> {
+        match self {
+            Self::String => Id::parse(value).map_err(DecodeError::Id),
+            Self::Hex => decode_hex(value),
+            Self::Uuid => Uuid::parse_str(value)
+                .map_err(|_| DecodeError::Format(self))
+                .and_then(|uuid| Id::from_uuid(uuid).map_err(DecodeError::Id)),
+            Self::Base64 => decode_base64(value),
+        }
+    }
+
+    /// Decode an [`Id`] from a value in any format. If no format matches, the
+    /// error from parsing the value as a string is returned, as it carries
+    /// the most detail.
+    pub fn detect(value: &str) -> Result<Id, DecodeError> {
+        Self::String.decode(value).or_else(|error| {
+            [Self::Uuid, Self::Hex, Self::Base64]
+                .into_iter()
+                .find_map(|format| format.decode(value).ok())
+                .ok_or(error)
+        })
+    }
+}
+
+impl Display for Format {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::String => "string",
+            Self::Hex => "hex",
+            Self::Uuid => "UUID",
+            Self::Base64 => "base64",
+        };
+
+        f.write_str(name)
+    }
+}
+
+impl Display for DecodeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Format(format) => write!(f, "not a valid {format} value"),
+            Self::Id(error) => Display::fmt(error, f),
+        }
+    }
+}
+
+impl std::error::Error for DecodeError {}
+
+fn decode_hex(value: &str) -> Result<Id, DecodeError> {
+    let digits = value.strip_prefix("0x").unwrap_or(value);
+
+    if digits.len() != 32 || !digits.bytes().all(|c| c.is_ascii_hexdigit()) {
+        return Err(DecodeError::Format(Format::Hex));
+    }
+
+    u128::from_str_radix(digits, 16)
+        .map_err(|_| DecodeError::Format(Format::Hex))
+        .and_then(|value| Id::try_from(value).map_err(DecodeError::Id))
+}
+
+fn decode_base64(value: &str) -> Result<Id, DecodeError> {
+    // Accept the URL-safe alphabet and missing padding as well
+    let normalized = value
+        .trim_end_matches('=')
+        .replace('-', "+")
+        .replace('_', "/");
+
+    STANDARD_NO_PAD
+        .decode(normalized)
+        .ok()
+        .and_then(|bytes| <[u8; 16]>::try_from(bytes).ok())
+        .ok_or(DecodeError::Format(Format::Base64))
+        .and_then(|bytes| Id::from_bytes(bytes).map_err(DecodeError::Id))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::format::Format;
+    use souvenir::Id;
+
+    #[test]
+    fn round_trip() {
+        let id = Id::parse("user_02v58c5a3fy30k560qrtg4").unwrap();
+
+        for format in [Format::String, Format::Hex, Format::Uuid, Format::Base64] {
+            let encoded = format.encode(id);
+
+            assert_eq!(id, format.decode(&encoded).unwrap());
+            assert_eq!(id, Format::detect(&encoded).unwrap());
+        }
+    }
+
+    #[test]
+    fn detect() {
+        let id = Id::parse("user_02v58c5a3fy30k560qrtg4").unwrap();
+        let hex = Format::Hex.encode(id);
+        let base64 = Format::Base64.encode(id);
+
+        assert_eq!(id, Format::detect(&format!("0x{hex}")).unwrap());
+        assert_eq!(id, Format::detect(base64.trim_end_matches('=')).unwrap());
+        assert_eq!(
+            "suffix at byte 5 is the wrong length: expected 22 but found 21",
+            Format::detect("user_02v58c5a3fy30k560qrtg")
+                .unwrap_err()
+                .to_string()
+        );
+    }
+}
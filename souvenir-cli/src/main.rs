@@ -0,0 +1,251 @@
+//! Command-line tool for generating, parsing and inspecting souvenir
+//! identifiers.
+
+mod format;
+
+use crate::format::Format;
+use clap::{Parser, Subcommand};
+use souvenir::{Error, Id, IdGenerator, ParseOptions, Prefix};
+use std::io::{self, BufRead, Write};
+use std::process::ExitCode;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Generate, parse and inspect prefixed identifiers.
+#[derive(Debug, Parser)]
+#[command(name = "souvenir", version)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Generate new identifiers with the provided prefix
+    New {
+        prefix: Prefix,
+
+        /// The number of identifiers to generate
+        #[arg(short = 'n', long, default_value_t = 1)]
+        count: usize,
+
+        /// Generate fully random identifiers instead of time-ordered ones
+        #[arg(long)]
+        random: bool,
+    },
+
+    /// Show the components and representations of an identifier in any
+    /// format
+    Inspect { id: String },
+
+    /// Convert an identifier between formats
+    Convert {
+        value: String,
+
+        /// The format of the input, detected automatically if omitted
+        #[arg(short, long)]
+        from: Option<Format>,
+
+        /// The format of the output
+        #[arg(short, long, default_value = "string")]
+        to: Format,
+    },
+
+    /// Replace the prefix of an identifier, keeping its suffix
+    Cast { id: String, prefix: Prefix },
+
+    /// Validate identifiers read from stdin, one per line, exiting with a
+    /// non-zero status if any are invalid
+    Validate {
+        /// Require every identifier to have this prefix
+        #[arg(short, long)]
+        prefix: Option<Prefix>,
+
+        /// Accept uppercase and ambiguous characters
+        #[arg(long)]
+        lenient: bool,
+
+        /// Only report the number of invalid identifiers
+        #[arg(short, long)]
+        quiet: bool,
+    },
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let result = match cli.command {
+        Command::New {
+            prefix,
+            count,
+            random,
+        } => new(prefix, count, random),
+        Command::Inspect { id } => inspect(&id),
+        Command::Convert { value, from, to } => convert(&value, from, to),
+        Command::Cast { id, prefix } => cast(&id, prefix),
+        Command::Validate {
+            prefix,
+            lenient,
+            quiet,
+        } => validate(prefix, lenient, quiet),
+    };
+
+    result.unwrap_or_else(|error| {
+        eprintln!("error: {error}");
+        ExitCode::from(2)
+    })
+}
+
+type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+fn new(prefix: Prefix, count: usize, random: bool) -> Result<ExitCode> {
+    let generator = IdGenerator::new(prefix);
+    let mut stdout = io::stdout().lock();
+
+    for _ in 0..count {
+        let id = match random {
+            true => Id::random(prefix),
+            false => generator.generate()?,
+        };
+
+        writeln!(stdout, "{id}")?;
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
+
+fn inspect(value: &str) -> Result<ExitCode> {
+    let id = Format::detect(value)?;
+
+    println!("id         {id}");
+    println!("prefix     {}", id.prefix());
+    println!("suffix     {}", id.suffix());
+    println!("u128       {}", id.to_u128());
+    println!("hex        {}", Format::Hex.encode(id));
+    println!("uuid       {}", Format::Uuid.encode(id));
+    println!("base64     {}", Format::Base64.encode(id));
+
+    if let Some(time) = timestamp(id) {
+        println!(
+            "timestamp  {} ({})",
+            humantime::format_rfc3339_millis(time),
+            id.timestamp()
+        );
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
+
+fn convert(value: &str, from: Option<Format>, to: Format) -> Result<ExitCode> {
+    let id = match from {
+        Some(format) => format.decode(value)?,
+        None => Format::detect(value)?,
+    };
+
+    println!("{}", to.encode(id));
+    Ok(ExitCode::SUCCESS)
+}
+
+fn cast(value: &str, prefix: Prefix) -> Result<ExitCode> {
+    let id = Format::detect(value)?;
+
+    println!("{}", id.cast(prefix));
+    Ok(ExitCode::SUCCESS)
+}
+
+fn validate(prefix: Option<Prefix>, lenient: bool, quiet: bool) -> Result<ExitCode> {
+    let options = match lenient {
+        true => ParseOptions::lenient(),
+        false => ParseOptions::strict(),
+    };
+
+    let mut total = 0;
+    let mut invalid = 0;
+
+    for (index, line) in io::stdin().lock().lines().enumerate() {
+        let line = line?;
+        let value = line.trim();
+
+        if value.is_empty() {
+            continue;
+        }
+
+        total += 1;
+
+        if let Err(error) = check(value, options, prefix) {
+            invalid += 1;
+
+            if !quiet {
+                eprintln!("line {}: `{value}`: {error}", index + 1);
+            }
+        }
+    }
+
+    if invalid == 0 {
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    eprintln!("{invalid} of {total} identifiers are invalid");
+    Ok(ExitCode::FAILURE)
+}
+
+/// Parse an identifier, checking its prefix if one is required.
+fn check(value: &str, options: ParseOptions, prefix: Option<Prefix>) -> souvenir::Result<Id> {
+    let id = Id::parse_with(value, options)?;
+
+    match prefix {
+        Some(expected) if id.prefix() != expected => Err(Error::PrefixMismatch {
+            expected,
+            found: id.prefix(),
+        }),
+        _ => Ok(id),
+    }
+}
+
+/// Get the time embedded in a time-ordered identifier. Since random
+/// identifiers also have a value in place of the timestamp, this is only
+/// returned if it falls between 2000 and one day from now.
+fn timestamp(id: Id) -> Option<SystemTime> {
+    const MIN: Duration = Duration::from_secs(946_684_800);
+    const MARGIN: Duration = Duration::from_secs(86_400);
+
+    let elapsed = Duration::from_millis(id.timestamp());
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).ok()?;
+
+    (MIN..now + MARGIN)
+        .contains(&elapsed)
+        .then(|| UNIX_EPOCH + elapsed)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{check, timestamp};
+    use souvenir::{Error, Id, ParseOptions, Prefix, Suffix};
+
+    #[test]
+    fn validate() {
+        let user = Prefix::parse("user").unwrap();
+        let org = Prefix::parse("org").unwrap();
+        let id = "user_02v58c5a3fy30k560qrtg4";
+
+        assert!(check(id, ParseOptions::strict(), Some(user)).is_ok());
+        assert!(check("USER_02V58C5A3FY30K560QRTG4", ParseOptions::strict(), None).is_err());
+        assert!(check("USER_02V58C5A3FY30K560QRTG4", ParseOptions::lenient(), None).is_ok());
+        assert_eq!(
+            Err(Error::PrefixMismatch {
+                expected: org,
+                found: user
+            }),
+            check(id, ParseOptions::strict(), Some(org))
+        );
+    }
+
+    #[test]
+    fn embedded_timestamp() {
+        let prefix = Prefix::parse("user").unwrap();
+        let ordered = Id::new(prefix, Suffix::from_timestamp(1_700_000_000_000, 0));
+        let random = Id::new(prefix, Suffix::new(u128::MAX));
+
+        assert!(timestamp(ordered).is_some());
+        assert!(timestamp(random).is_none());
+    }
+}
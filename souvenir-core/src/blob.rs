@@ -0,0 +1,43 @@
+use crate::error::{Error, Result};
+use crate::id::Id;
+
+/// A wrapper which stores an identifier in SQLite as a 16-byte `BLOB`
+/// instead of the default `TEXT`.
+///
+/// `T` can be an [`Id`], a [`TypedId`](crate::typed::TypedId) or a
+/// [`KnownId`](crate::registry::KnownId).
+///
+/// Both this wrapper and the identifier types themselves decode from either
+/// `TEXT` or `BLOB` columns, so existing databases can be migrated
+/// incrementally. With `diesel`, the storage is chosen by the SQL type of
+/// the column instead, so identifiers can be used directly with `Binary`
+/// columns.
+#[derive(Copy, Clone, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct BlobId<T = Id>(pub T);
+
+/// Decode an [`Id`] stored in SQLite. Since a string identifier is never 16
+/// bytes long, 16-byte values are decoded as bytes and any other value is
+/// parsed as a string.
+pub(crate) fn decode_sqlite(bytes: &[u8]) -> Result<Id> {
+    match bytes.len() {
+        16 => Id::try_from(bytes),
+        _ => core::str::from_utf8(bytes)
+            .map_err(|_| Error::InvalidData)
+            .and_then(Id::parse),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{blob::decode_sqlite, error::Error, id::Id};
+
+    #[test]
+    fn decode() {
+        let id = Id::parse("user_02v58c5a3fy30k560qrtg4").unwrap();
+
+        assert_eq!(Ok(id), decode_sqlite(id.as_bytes()));
+        assert_eq!(Ok(id), decode_sqlite(id.to_string().as_bytes()));
+        assert_eq!(Err(Error::InvalidData), decode_sqlite(&[0xff; 24]));
+        assert!(decode_sqlite(&[0; 15]).is_err());
+    }
+}
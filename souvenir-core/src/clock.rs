@@ -0,0 +1,82 @@
+use core::sync::atomic::{AtomicU64, Ordering};
+
+/// A source of millisecond Unix timestamps, used when generating
+/// time-ordered identifiers.
+pub trait Clock {
+    /// Get the current time as milliseconds since the Unix epoch.
+    fn now(&self) -> u64;
+}
+
+impl<F: Fn() -> u64> Clock for F {
+    fn now(&self) -> u64 {
+        self()
+    }
+}
+
+/// A [`Clock`] which reads the current system time.
+#[cfg(feature = "std")]
+#[derive(Copy, Clone, Debug, Default, Hash, PartialEq, Eq)]
+pub struct SystemClock;
+
+#[cfg(feature = "std")]
+impl Clock for SystemClock {
+    fn now(&self) -> u64 {
+        use std::time::{SystemTime, UNIX_EPOCH};
+
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_millis() as u64)
+            .unwrap_or_default()
+    }
+}
+
+/// A [`Clock`] which always returns the same time.
+#[derive(Copy, Clone, Debug, Default, Hash, PartialEq, Eq)]
+pub struct FixedClock(u64);
+
+impl FixedClock {
+    /// Create a [`FixedClock`] which always returns the provided timestamp.
+    pub const fn new(timestamp: u64) -> Self {
+        Self(timestamp)
+    }
+}
+
+impl Clock for FixedClock {
+    fn now(&self) -> u64 {
+        self.0
+    }
+}
+
+/// A [`Clock`] which starts at a given time and advances by a fixed step
+/// every time it is read.
+///
+/// ```
+/// # use souvenir_core::clock::{Clock, SteppingClock};
+/// let clock = SteppingClock::new(1000, 5);
+///
+/// assert_eq!(1000, clock.now());
+/// assert_eq!(1005, clock.now());
+/// assert_eq!(1010, clock.now());
+/// ```
+#[derive(Debug, Default)]
+pub struct SteppingClock {
+    next: AtomicU64,
+    step: u64,
+}
+
+impl SteppingClock {
+    /// Create a [`SteppingClock`] which first returns `start`, and then
+    /// advances by `step` milliseconds on each read.
+    pub const fn new(start: u64, step: u64) -> Self {
+        Self {
+            next: AtomicU64::new(start),
+            step,
+        }
+    }
+}
+
+impl Clock for SteppingClock {
+    fn now(&self) -> u64 {
+        self.next.fetch_add(self.step, Ordering::Relaxed)
+    }
+}
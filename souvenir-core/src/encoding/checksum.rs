@@ -0,0 +1,123 @@
+use crate::{
+    encoding::decode_id_with,
+    error::{Component, Error, Result},
+    id::Id,
+    options::ParseOptions,
+};
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+/// The alphabet of check symbols used by checked [`Id`] strings. The first
+/// 32 symbols are the same as [`ALPHABET`](crate::encoding::ALPHABET),
+/// followed by the 5 extra check symbols defined by Crockford.
+pub const CHECK_ALPHABET: &[u8; 37] = b"0123456789abcdefghjkmnpqrstvwxyz*~$=u";
+
+pub fn checksum(id: Id) -> char {
+    CHECK_ALPHABET[(id.to_u128() % 37) as usize] as char
+}
+
+#[cfg(feature = "alloc")]
+pub fn encode_id_checked(id: Id) -> String {
+    let mut output = crate::encoding::encode_id(id);
+    output.push(checksum(id));
+    output
+}
+
+pub fn decode_id_checked(id: &str) -> Result<Id> {
+    decode_id_checked_with(id, ParseOptions::strict())
+}
+
+pub fn decode_id_checked_with(id: &str, options: ParseOptions) -> Result<Id> {
+    let mut found = id.chars().next_back().ok_or(Error::InvalidLength {
+        component: Component::Checksum,
+        position: 0,
+        expected: 1,
+        found: 0,
+    })?;
+
+    let position = id.len() - found.len_utf8();
+    let decoded = decode_id_with(&id[..position], options.checksum(false))?;
+    let expected = checksum(decoded);
+
+    if options.case_insensitive {
+        found = found.to_ascii_lowercase();
+    }
+
+    if found != expected {
+        return Err(Error::ChecksumMismatch {
+            position,
+            expected,
+            found,
+        });
+    }
+
+    Ok(decoded)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        encoding::decode_id_checked,
+        error::{Component, Error},
+    };
+    #[cfg(feature = "alloc")]
+    use crate::{encoding::encode_id_checked, id::Id, suffix::Suffix};
+    #[cfg(feature = "alloc")]
+    use rand::random;
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn encode_one() {
+        let id = Id::parse("user_02v58c5a3fy30k560qrtg4").unwrap();
+        assert_eq!("user_02v58c5a3fy30k560qrtg44", encode_id_checked(id));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn round_trip() {
+        for _ in 0..10000 {
+            let id = Id::new("user".parse().unwrap(), Suffix::new(random()));
+            assert_eq!(Ok(id), decode_id_checked(&encode_id_checked(id)));
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn detect_typos() {
+        let id = Id::new("user".parse().unwrap(), Suffix::new(random()));
+        let encoded = encode_id_checked(id);
+
+        for i in 5..encoded.len() - 2 {
+            let mut swapped = encoded.clone().into_bytes();
+            swapped.swap(i, i + 1);
+
+            if swapped == encoded.as_bytes() {
+                continue;
+            }
+
+            let swapped = String::from_utf8(swapped).unwrap();
+            assert!(decode_id_checked(&swapped).is_err());
+        }
+    }
+
+    #[test]
+    fn decode_mismatch() {
+        assert_eq!(
+            Err(Error::ChecksumMismatch {
+                position: 27,
+                expected: '4',
+                found: '0'
+            }),
+            decode_id_checked("user_02v58c5a3fy30k560qrtg40")
+        );
+        assert_eq!(
+            Err(Error::InvalidLength {
+                component: Component::Checksum,
+                position: 0,
+                expected: 1,
+                found: 0
+            }),
+            decode_id_checked("")
+        );
+    }
+}
@@ -1,37 +1,110 @@
 use crate::{
-    encoding::{decode_prefix, decode_suffix, encode_prefix, encode_suffix, validate_prefix},
-    error::{Error, Result},
+    encoding::{
+        decode_id_checked_with, decode_prefix_const, decode_prefix_with, decode_suffix_const,
+        decode_suffix_with, encode_prefix_into, encode_suffix_into, validate_prefix,
+    },
+    error::{Error, Hint, Result, Snippet},
     id::Id,
+    options::ParseOptions,
     suffix::Suffix,
 };
+#[cfg(feature = "alloc")]
+use alloc::string::String;
 
+#[cfg(feature = "alloc")]
 pub fn encode_id(id: Id) -> String {
-    let prefix = encode_prefix(id.prefix());
-    let suffix = encode_suffix(id.suffix());
+    let mut buf = [0; 27];
+    let size = encode_id_into(id, &mut buf);
 
-    format!("{}_{}", prefix, suffix)
+    // UNSAFE: All bytes are guaranteed to be in ASCII range.
+    unsafe { String::from_utf8_unchecked(buf[..size].to_vec()) }
+}
+
+pub fn encode_id_into(id: Id, buf: &mut [u8; 27]) -> usize {
+    let mut prefix = [0; 4];
+    let size = encode_prefix_into(id.prefix(), &mut prefix);
+
+    buf[..size].copy_from_slice(&prefix[..size]);
+    buf[size] = b'_';
+    encode_suffix_into(
+        id.suffix(),
+        (&mut buf[size + 1..size + 23]).try_into().unwrap(),
+    );
+
+    size + 23
 }
 
 pub fn decode_id(id: &str) -> Result<Id> {
-    let (prefix, suffix) = id.rsplit_once('_').ok_or(Error::InvalidFormat)?;
+    decode_id_with(id, ParseOptions::strict())
+}
 
-    let prefix = decode_prefix(prefix)?;
-    let suffix = decode_suffix(suffix)?;
+pub fn decode_id_with(id: &str, options: ParseOptions) -> Result<Id> {
+    if options.checksum {
+        return decode_id_checked_with(id, options);
+    }
+
+    let (prefix, suffix) = id
+        .rsplit_once('_')
+        .ok_or(missing_separator(id.as_bytes()))?;
+
+    let suffix_start = prefix.len() + 1;
+    let prefix = decode_prefix_with(prefix, options)?;
+    let suffix = decode_suffix_with(suffix, options).map_err(|error| error.offset(suffix_start))?;
 
     Ok(Id::new(prefix, suffix))
 }
 
-pub fn validate_id(bytes: [u8; 16]) -> Result<Id> {
+pub const fn decode_id_const(id: &[u8]) -> Result<Id> {
+    let mut i = id.len();
+
+    while i > 0 && id[i - 1] != b'_' {
+        i -= 1;
+    }
+
+    if i == 0 {
+        return Err(missing_separator(id));
+    }
+
+    let (prefix, suffix) = id.split_at(i);
+    let (prefix, _) = prefix.split_at(i - 1);
+
+    let prefix = match decode_prefix_const(prefix) {
+        Ok(prefix) => prefix,
+        Err(error) => return Err(error),
+    };
+
+    match decode_suffix_const(suffix) {
+        Ok(suffix) => Ok(Id::new(prefix, suffix)),
+        Err(error) => Err(error.offset(i)),
+    }
+}
+
+pub(crate) const fn missing_separator(id: &[u8]) -> Error {
+    Error::InvalidFormat {
+        position: id.len(),
+        found: Snippet::from_bytes(id),
+        hint: Hint::MissingSeparator,
+    }
+}
+
+pub const fn validate_id(bytes: [u8; 16]) -> Result<Id> {
     let suffix = u128::from_be_bytes(bytes);
     let prefix = (suffix >> 108) as u32;
 
-    validate_prefix(prefix).map(|prefix| Id::new(prefix, Suffix::new(suffix)))
+    match validate_prefix(prefix) {
+        Ok(prefix) => Ok(Id::new(prefix, Suffix::new(suffix))),
+        Err(error) => Err(error),
+    }
 }
 
 #[cfg(test)]
 mod test {
-    use crate::{error::Error, id::Id};
+    use crate::{
+        error::{Component, Error, Hint, Snippet},
+        id::Id,
+    };
 
+    #[cfg(feature = "alloc")]
     fn encode_id(bytes: [u8; 16]) -> String {
         crate::encoding::encode_id(Id::from_bytes(bytes).unwrap())
     }
@@ -40,6 +113,7 @@ mod test {
         crate::encoding::decode_id(id).map(Id::to_bytes)
     }
 
+    #[cfg(feature = "alloc")]
     #[test]
     fn encode_one() {
         let bytes: [u8; 16] = [
@@ -50,6 +124,17 @@ mod test {
         assert_eq!("user_02v58c5a3fy30k560qrtg4", encode_id(bytes))
     }
 
+    #[test]
+    fn encode_into_buffer() {
+        let id = Id::parse("a_0000000000000000000000").unwrap();
+        let mut buf = [0; 27];
+        let size = crate::encoding::encode_id_into(id, &mut buf);
+
+        assert_eq!(b"a_0000000000000000000000", &buf[..size]);
+        assert_eq!("a_0000000000000000000000", &*id.encode());
+        assert_eq!(format!("{id:>27}"), format!("{:>27}", id.to_string()));
+    }
+
     #[test]
     fn decode_random() {
         assert_eq!(
@@ -86,8 +171,73 @@ mod test {
     #[test]
     fn decode_invalid() {
         assert_eq!(
-            Err(Error::InvalidFormat),
+            Err(Error::InvalidFormat {
+                position: 26,
+                found: Snippet::new("80000000000000000000000000"),
+                hint: Hint::MissingSeparator,
+            }),
             decode_id("80000000000000000000000000")
-        )
+        );
+        assert_eq!(
+            Err(Error::InvalidChar {
+                component: Component::Suffix,
+                position: 5,
+                found: '8',
+                hint: Some(Hint::FirstSuffixChar { max: '7' }),
+            }),
+            decode_id("user_82v58c5a3fy30k560qrtg4")
+        );
+        assert_eq!(
+            Err(Error::InvalidLength {
+                component: Component::Suffix,
+                position: 5,
+                expected: 22,
+                found: 21,
+            }),
+            decode_id("user_02v58c5a3fy30k560qrtg")
+        );
+    }
+
+    #[test]
+    fn error_message() {
+        assert_eq!(
+            "invalid character `l` in suffix at byte 26: \
+             the characters i, l, o and u are not allowed",
+            Id::parse("user_02v58c5a3fy30k560qrtgl")
+                .unwrap_err()
+                .to_string()
+        );
+        assert_eq!(
+            "invalid prefix `uSer` at byte 1: uppercase letters are not allowed",
+            Id::parse("uSer_02v58c5a3fy30k560qrtg4")
+                .unwrap_err()
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn decode_const() {
+        const ID: Id = Id::from_str_const("user_02v58c5a3fy30k560qrtg4");
+        assert_eq!(Ok(ID), Id::parse("user_02v58c5a3fy30k560qrtg4"));
+
+        for id in [
+            "a_0000000000000000000000",
+            "zzzz_7zzzzzzzzzzzzzzzzzzzzz",
+            "80000000000000000000000000",
+            "user_",
+            "_02v58c5a3fy30k560qrtg4",
+            "users_02v58c5a3fy30k560qrtg4",
+            "User_02v58c5a3fy30k560qrtg4",
+            "user_82v58c5a3fy30k560qrtg4",
+            "user_02v58c5a3fy30k560qrtgu",
+            "user_02v58c5a3fy30k560qrtg",
+            "a_b_0000000000000000000000",
+        ] {
+            assert_eq!(
+                crate::encoding::decode_id(id),
+                crate::encoding::decode_id_const(id.as_bytes()),
+                "{id}"
+            );
+        }
     }
 }
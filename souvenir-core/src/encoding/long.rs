@@ -0,0 +1,296 @@
+use crate::{
+    encoding::{ALPHABET, ALPHABET_INV, char_at, invalid_prefix, missing_separator, suffix_hint},
+    error::{Component, Error, Hint, Result},
+    long::{LongId, LongPrefix},
+};
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+/// The characters allowed in a [`LongPrefix`], in ascending order. The value
+/// of each character is its index plus one, with zero meaning "no character".
+pub const LONG_PREFIX: &[u8; 36] = b"0123456789abcdefghijklmnopqrstuvwxyz";
+const LONG_PREFIX_INV: &[u8; 256] = &{
+    let mut output = [255; 256];
+
+    let mut i = 0;
+    while i < 36 {
+        output[LONG_PREFIX[i] as usize] = i as u8 + 1;
+        i += 1;
+    }
+
+    output
+};
+
+const RADIX: u64 = 37;
+const LENGTH: usize = 12;
+
+#[cfg(feature = "alloc")]
+pub fn encode_long_prefix(prefix: LongPrefix) -> String {
+    let mut buf = [0u8; LENGTH];
+    let size = encode_long_prefix_into(prefix, &mut buf);
+
+    // UNSAFE: All bytes are guaranteed to be in ASCII range.
+    unsafe { String::from_utf8_unchecked(buf[..size].to_vec()) }
+}
+
+pub fn encode_long_prefix_into(prefix: LongPrefix, buf: &mut [u8; LENGTH]) -> usize {
+    let mut raw = prefix.to_u64();
+    let mut size = 0;
+
+    for b in buf.iter_mut().rev() {
+        let char = raw % RADIX;
+        raw /= RADIX;
+
+        if char == 0 && size == 0 {
+            continue;
+        }
+
+        *b = LONG_PREFIX[char as usize - 1];
+        size += 1;
+    }
+
+    size
+}
+
+pub fn decode_long_prefix(prefix: &str) -> Result<LongPrefix> {
+    let bytes = prefix.as_bytes();
+
+    let value = bytes.iter().enumerate().try_fold(0u64, |acc, (i, &ch)| {
+        let value = LONG_PREFIX_INV[ch as usize];
+
+        let hint = match ch {
+            b'0'..=b'9' if i == 0 => Hint::LeadingDigit,
+            _ if value != 0xff => return Ok(acc.wrapping_mul(RADIX).wrapping_add(value as u64)),
+            b'A'..=b'Z' => Hint::Uppercase,
+            b'_' => Hint::ExtraSeparator,
+            _ => Hint::Unexpected {
+                found: char_at(bytes, i),
+            },
+        };
+
+        Err(invalid_prefix(bytes, i, hint))
+    })?;
+
+    if bytes.is_empty() {
+        return Err(invalid_prefix(bytes, 0, Hint::Empty));
+    }
+
+    if bytes.len() > LENGTH {
+        return Err(invalid_prefix(bytes, LENGTH, Hint::TooLong { max: LENGTH }));
+    }
+
+    let value = value * RADIX.pow((LENGTH - bytes.len()) as u32);
+    Ok(unsafe { LongPrefix::new_unchecked(value) })
+}
+
+pub fn validate_long_prefix(prefix: u64) -> Result<LongPrefix> {
+    let mut raw = prefix;
+    let mut digits = [0u64; LENGTH];
+
+    for digit in digits.iter_mut().rev() {
+        *digit = raw % RADIX;
+        raw /= RADIX;
+    }
+
+    // The value must fit in 12 digits, and must start with a letter.
+    if raw != 0 || digits[0] <= 10 {
+        return Err(Error::InvalidData);
+    }
+
+    // Once a character is missing, all following characters must be missing.
+    if digits.windows(2).any(|pair| pair[0] == 0 && pair[1] != 0) {
+        return Err(Error::InvalidData);
+    }
+
+    Ok(unsafe { LongPrefix::new_unchecked(prefix) })
+}
+
+#[cfg(feature = "alloc")]
+pub fn encode_long_id(id: LongId) -> String {
+    let mut buf = [0; LENGTH + 14];
+    let size = encode_long_id_into(id, &mut buf);
+
+    // UNSAFE: All bytes are guaranteed to be in ASCII range.
+    unsafe { String::from_utf8_unchecked(buf[..size].to_vec()) }
+}
+
+pub fn encode_long_id_into(id: LongId, buf: &mut [u8; LENGTH + 14]) -> usize {
+    let mut prefix = [0; LENGTH];
+    let size = encode_long_prefix_into(id.prefix(), &mut prefix);
+
+    buf[..size].copy_from_slice(&prefix[..size]);
+    buf[size] = b'_';
+
+    let mut raw = id.suffix();
+
+    for b in buf[size + 1..size + 14].iter_mut().rev() {
+        *b = ALPHABET[(raw & 0x1f) as usize];
+        raw >>= 5;
+    }
+
+    size + 14
+}
+
+pub fn decode_long_id(id: &str) -> Result<LongId> {
+    let (prefix, suffix) = id
+        .rsplit_once('_')
+        .ok_or(missing_separator(id.as_bytes()))?;
+
+    let suffix_start = prefix.len() + 1;
+    let prefix = decode_long_prefix(prefix)?;
+
+    if suffix.len() != 13 {
+        return Err(Error::InvalidLength {
+            component: Component::Suffix,
+            position: suffix_start,
+            expected: 13,
+            found: suffix.len(),
+        });
+    }
+
+    let bytes = suffix.as_bytes();
+    let suffix = bytes.iter().enumerate().try_fold(0u64, |acc, (i, &ch)| {
+        let value = ALPHABET_INV[ch as usize];
+
+        if value == 0xff || (i == 0 && value > 15) {
+            let hint = if value == 0xff {
+                suffix_hint(ch)
+            } else {
+                Some(Hint::FirstSuffixChar { max: 'f' })
+            };
+
+            return Err(Error::InvalidChar {
+                component: Component::Suffix,
+                position: suffix_start + i,
+                found: char_at(bytes, i),
+                hint,
+            });
+        }
+
+        Ok((acc << 5) | value as u64)
+    })?;
+
+    Ok(LongId::new(prefix, suffix))
+}
+
+pub fn validate_long_id(bytes: [u8; 16]) -> Result<LongId> {
+    let value = u128::from_be_bytes(bytes);
+
+    validate_long_prefix((value >> 64) as u64).map(|prefix| LongId::new(prefix, value as u64))
+}
+
+#[cfg(test)]
+mod test {
+    #[cfg(feature = "alloc")]
+    use crate::{
+        encoding::{decode_long_id, encode_long_id},
+        error::Component,
+        long::{LongId, LongPrefix},
+    };
+    use crate::{
+        encoding::{decode_long_prefix, validate_long_prefix},
+        error::{Error, Hint, Snippet},
+    };
+    #[cfg(feature = "alloc")]
+    use rand::random;
+
+    #[test]
+    fn prefix_round_trip() {
+        for prefix in [
+            "a",
+            "z",
+            "inv",
+            "invoice",
+            "invoice2",
+            "zzzzzzzzzzzz",
+            "a00000000009",
+        ] {
+            let decoded = decode_long_prefix(prefix).unwrap();
+
+            assert_eq!(Ok(decoded), validate_long_prefix(decoded.to_u64()));
+            assert_eq!(prefix, decoded.to_string());
+        }
+    }
+
+    #[test]
+    fn prefix_invalid() {
+        let invalid = |prefix, position, hint| {
+            Err(Error::InvalidPrefix {
+                position,
+                found: Snippet::new(prefix),
+                hint,
+            })
+        };
+
+        assert_eq!(invalid("", 0, Hint::Empty), decode_long_prefix(""));
+        assert_eq!(
+            invalid("0abc", 0, Hint::LeadingDigit),
+            decode_long_prefix("0abc")
+        );
+        assert_eq!(
+            invalid("Invoice", 0, Hint::Uppercase),
+            decode_long_prefix("Invoice")
+        );
+        assert_eq!(
+            invalid("line_item", 4, Hint::ExtraSeparator),
+            decode_long_prefix("line_item")
+        );
+        assert_eq!(
+            invalid("aaaaaaaaaaaaa", 12, Hint::TooLong { max: 12 }),
+            decode_long_prefix("aaaaaaaaaaaaa")
+        );
+
+        assert_eq!(Err(Error::InvalidData), validate_long_prefix(0));
+        assert_eq!(Err(Error::InvalidData), validate_long_prefix(1));
+        assert_eq!(Err(Error::InvalidData), validate_long_prefix(u64::MAX));
+    }
+
+    #[test]
+    fn prefix_ordering() {
+        let mut prefixes = ["b", "a1", "a", "ab", "a0", "zz", "z9", "aa"];
+        let mut decoded = prefixes.map(|prefix| decode_long_prefix(prefix).unwrap());
+
+        prefixes.sort();
+        decoded.sort();
+
+        assert_eq!(prefixes.map(String::from), decoded.map(|p| p.to_string()));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn id_round_trip() {
+        let prefix: LongPrefix = "invoice2".parse().unwrap();
+
+        for _ in 0..10000 {
+            let id = LongId::new(prefix, random());
+            let encoded = encode_long_id(id);
+
+            assert_eq!(Ok(id), decode_long_id(&encoded));
+            assert_eq!(Ok(id), LongId::from_bytes(id.to_bytes()));
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn id_bounds() {
+        let prefix: LongPrefix = "lineitem".parse().unwrap();
+
+        assert_eq!(
+            "lineitem_0000000000000",
+            encode_long_id(LongId::new(prefix, 0))
+        );
+        assert_eq!(
+            "lineitem_fzzzzzzzzzzzz",
+            encode_long_id(LongId::new(prefix, u64::MAX))
+        );
+        assert_eq!(
+            Err(Error::InvalidChar {
+                component: Component::Suffix,
+                position: 9,
+                found: 'g',
+                hint: Some(Hint::FirstSuffixChar { max: 'f' }),
+            }),
+            decode_long_id("lineitem_g000000000000")
+        );
+    }
+}
@@ -1,11 +1,16 @@
+mod checksum;
 mod id;
+mod long;
 mod prefix;
 mod suffix;
 
+pub use checksum::*;
 pub use id::*;
+pub use long::*;
 pub use prefix::*;
 pub use suffix::*;
 
+use crate::error::Hint;
 pub use crate::id::Id;
 
 /// The alphabet of characters used in the suffix part of an [`Id`].
@@ -21,3 +26,36 @@ pub const ALPHABET_INV: &[u8; 256] = &{
 
     output
 };
+
+/// Decode the character starting at the provided byte offset, which must be
+/// at a character boundary.
+pub(crate) const fn char_at(bytes: &[u8], position: usize) -> char {
+    let first = bytes[position];
+
+    let (len, mut value) = match first {
+        0x00..=0x7f => return first as char,
+        0xc0..=0xdf => (2, (first & 0x1f) as u32),
+        0xe0..=0xef => (3, (first & 0x0f) as u32),
+        _ => (4, (first & 0x07) as u32),
+    };
+
+    let mut i = 1;
+    while i < len && position + i < bytes.len() {
+        value = (value << 6) | (bytes[position + i] & 0x3f) as u32;
+        i += 1;
+    }
+
+    match char::from_u32(value) {
+        Some(char) => char,
+        None => char::REPLACEMENT_CHARACTER,
+    }
+}
+
+/// Get the hint for a character which is not in the suffix alphabet.
+pub(crate) const fn suffix_hint(char: u8) -> Option<Hint> {
+    match char {
+        b'A'..=b'Z' => Some(Hint::Uppercase),
+        b'i' | b'l' | b'o' | b'u' => Some(Hint::Ambiguous),
+        _ => None,
+    }
+}
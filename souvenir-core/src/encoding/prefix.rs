@@ -1,7 +1,11 @@
 use crate::{
-    error::{Error, Result},
+    encoding::char_at,
+    error::{Error, Hint, Result, Snippet},
+    options::ParseOptions,
     prefix::Prefix,
 };
+#[cfg(feature = "alloc")]
+use alloc::string::String;
 
 pub const PREFIX: &[u8; 32] = b"\xffabcdefghijklmnopqrstuvwxyz\xff\xff\xff\xff\xff";
 const PREFIX_INV: &[u8; 256] = &{
@@ -17,10 +21,17 @@ const PREFIX_INV: &[u8; 256] = &{
     output
 };
 
+#[cfg(feature = "alloc")]
 pub fn encode_prefix(prefix: Prefix) -> String {
-    let mut raw = prefix.to_u32();
-
     let mut buf = [0u8; 4];
+    let size = encode_prefix_into(prefix, &mut buf);
+
+    // UNSAFE: All bytes are guaranteed to be in ASCII range.
+    unsafe { String::from_utf8_unchecked(buf[..size].to_vec()) }
+}
+
+pub fn encode_prefix_into(prefix: Prefix, buf: &mut [u8; 4]) -> usize {
+    let mut raw = prefix.to_u32();
     let mut size = 0;
 
     for b in buf.iter_mut().rev() {
@@ -35,35 +46,73 @@ pub fn encode_prefix(prefix: Prefix) -> String {
         size += 1;
     }
 
-    // UNSAFE: All bytes are guaranteed to be in ASCII range.
-    unsafe { String::from_utf8_unchecked(buf[..size].to_vec()) }
+    size
 }
 
 pub fn decode_prefix(prefix: &str) -> Result<Prefix> {
+    decode_prefix_with(prefix, ParseOptions::strict())
+}
+
+pub fn decode_prefix_with(prefix: &str, options: ParseOptions) -> Result<Prefix> {
+    decode_prefix_bytes(prefix.as_bytes(), options)
+}
+
+pub const fn decode_prefix_const(prefix: &[u8]) -> Result<Prefix> {
+    decode_prefix_bytes(prefix, ParseOptions::strict())
+}
+
+const fn decode_prefix_bytes(prefix: &[u8], options: ParseOptions) -> Result<Prefix> {
     let size = prefix.len();
+    let mut result = 0u32;
+    let mut i = 0;
 
-    if !(1..=4).contains(&size) {
-        return Err(Error::InvalidPrefix);
+    while i < size {
+        let ch = if options.case_insensitive {
+            prefix[i].to_ascii_lowercase()
+        } else {
+            prefix[i]
+        };
+
+        let value = PREFIX_INV[ch as usize];
+
+        if value == 0xff {
+            let hint = match ch {
+                b'A'..=b'Z' => Hint::Uppercase,
+                b'0'..=b'9' => Hint::Digit,
+                b'_' => Hint::ExtraSeparator,
+                _ => Hint::Unexpected {
+                    found: char_at(prefix, i),
+                },
+            };
+
+            return Err(invalid_prefix(prefix, i, hint));
+        }
+
+        result = (result << 5) | value as u32;
+        i += 1;
     }
 
-    prefix
-        .as_bytes()
-        .iter()
-        .try_fold(0u32, |acc, &ch| {
-            let value = PREFIX_INV[ch as usize];
+    if size == 0 {
+        return Err(invalid_prefix(prefix, 0, Hint::Empty));
+    }
 
-            if value != 0xff {
-                Ok((acc << 5) | value as u32)
-            } else {
-                Err(Error::InvalidPrefix)
-            }
-        })
-        .map(|result| result << ((4 - size) * 5))
-        .map(|result| unsafe { Prefix::new_unchecked(result) })
+    if size > 4 {
+        return Err(invalid_prefix(prefix, 4, Hint::TooLong { max: 4 }));
+    }
+
+    Ok(unsafe { Prefix::new_unchecked(result << ((4 - size) * 5)) })
+}
+
+pub(crate) const fn invalid_prefix(prefix: &[u8], position: usize, hint: Hint) -> Error {
+    Error::InvalidPrefix {
+        position,
+        found: Snippet::from_bytes(prefix),
+        hint,
+    }
 }
 
 #[allow(clippy::overly_complex_bool_expr)]
-pub fn validate_prefix(prefix: u32) -> Result<Prefix> {
+pub const fn validate_prefix(prefix: u32) -> Result<Prefix> {
     let a = prefix >> 15;
     let b = (prefix >> 10) & 0x1f;
     let c = (prefix >> 5) & 0x1f;
@@ -86,12 +135,15 @@ pub fn validate_prefix(prefix: u32) -> Result<Prefix> {
 
 #[cfg(test)]
 mod test {
+    #[cfg(feature = "alloc")]
+    use crate::encoding::{PREFIX, validate_prefix};
     use crate::{
-        encoding::{PREFIX, validate_prefix},
-        error::{Error, Result},
+        error::{Error, Hint, Result, Snippet},
+        options::ParseOptions,
         prefix::Prefix,
     };
 
+    #[cfg(feature = "alloc")]
     fn encode_prefix(raw: u32) -> String {
         crate::encoding::encode_prefix(Prefix::new(raw).unwrap())
     }
@@ -100,6 +152,7 @@ mod test {
         crate::encoding::decode_prefix(str).map(Prefix::to_u32)
     }
 
+    #[cfg(feature = "alloc")]
     #[test]
     fn encode_smoke() {
         assert_eq!("user", encode_prefix(0b10101_10011_00101_10010));
@@ -108,6 +161,7 @@ mod test {
         assert_eq!("u", encode_prefix(0b10101_00000_00000_00000));
     }
 
+    #[cfg(feature = "alloc")]
     #[test]
     fn encode_iter() {
         fn format_one(char: u32) -> String {
@@ -150,14 +204,54 @@ mod test {
         assert_eq!(Ok(0b10101_00000_00000_00000), decode_prefix("u"));
     }
 
+    fn invalid(prefix: &str, position: usize, hint: Hint) -> Result<u32> {
+        Err(Error::InvalidPrefix {
+            position,
+            found: Snippet::new(prefix),
+            hint,
+        })
+    }
+
     #[test]
     fn decode_invalid() {
-        assert_eq!(Err(Error::InvalidPrefix), decode_prefix(""));
-        assert_eq!(Err(Error::InvalidPrefix), decode_prefix("aaaaa"));
-        assert_eq!(Err(Error::InvalidPrefix), decode_prefix("\0"));
-        assert_eq!(Err(Error::InvalidPrefix), decode_prefix("A"));
-        assert_eq!(Err(Error::InvalidPrefix), decode_prefix("!"));
-        assert_eq!(Err(Error::InvalidPrefix), decode_prefix(" "));
+        assert_eq!(invalid("", 0, Hint::Empty), decode_prefix(""));
+        assert_eq!(
+            invalid("aaaaa", 4, Hint::TooLong { max: 4 }),
+            decode_prefix("aaaaa")
+        );
+        assert_eq!(
+            invalid("\0", 0, Hint::Unexpected { found: '\0' }),
+            decode_prefix("\0")
+        );
+        assert_eq!(invalid("A", 0, Hint::Uppercase), decode_prefix("A"));
+        assert_eq!(
+            invalid("!", 0, Hint::Unexpected { found: '!' }),
+            decode_prefix("!")
+        );
+        assert_eq!(
+            invalid(" ", 0, Hint::Unexpected { found: ' ' }),
+            decode_prefix(" ")
+        );
+        assert_eq!(invalid("us3r", 2, Hint::Digit), decode_prefix("us3r"));
+        assert_eq!(
+            invalid("line_item", 4, Hint::ExtraSeparator),
+            decode_prefix("line_item")
+        );
+        assert_eq!(
+            invalid("üser", 0, Hint::Unexpected { found: 'ü' }),
+            decode_prefix("üser")
+        );
+    }
+
+    #[test]
+    fn decode_lenient() {
+        let options = ParseOptions::lenient();
+        let decode = |str| crate::encoding::decode_prefix_with(str, options).map(Prefix::to_u32);
+
+        assert_eq!(Ok(0b10101_10011_00101_10010), decode("USER"));
+        assert_eq!(Ok(0b10101_10011_00101_10010), decode("uSeR"));
+        assert_eq!(invalid("US3R", 2, Hint::Digit), decode("US3R"));
+        assert_eq!(invalid("USER", 0, Hint::Uppercase), decode_prefix("USER"));
     }
 
     #[test]
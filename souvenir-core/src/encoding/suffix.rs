@@ -1,52 +1,102 @@
 use crate::{
-    encoding::{ALPHABET, ALPHABET_INV},
-    error::{Error, Result},
+    encoding::{ALPHABET, ALPHABET_INV, char_at, suffix_hint},
+    error::{Component, Error, Hint, Result},
+    options::ParseOptions,
     suffix::Suffix,
 };
+#[cfg(feature = "alloc")]
+use alloc::string::String;
 
+#[cfg(feature = "alloc")]
 pub fn encode_suffix(suffix: Suffix) -> String {
-    let mut raw = suffix.to_u128();
     let mut buf = [0; 22];
+    encode_suffix_into(suffix, &mut buf);
+
+    // UNSAFE: All bytes are guaranteed to be in ASCII range.
+    unsafe { String::from_utf8_unchecked(buf.to_vec()) }
+}
+
+pub fn encode_suffix_into(suffix: Suffix, buf: &mut [u8; 22]) {
+    let mut raw = suffix.to_u128();
 
     for b in buf.iter_mut().rev() {
         *b = ALPHABET[(raw & 0x1f) as usize];
         raw >>= 5;
     }
-
-    // UNSAFE: All bytes are guaranteed to be in ASCII range.
-    unsafe { String::from_utf8_unchecked(buf.to_vec()) }
 }
 
 pub fn decode_suffix(suffix: &str) -> Result<Suffix> {
+    decode_suffix_with(suffix, ParseOptions::strict())
+}
+
+pub fn decode_suffix_with(suffix: &str, options: ParseOptions) -> Result<Suffix> {
+    decode_suffix_bytes(suffix.as_bytes(), options)
+}
+
+pub const fn decode_suffix_const(suffix: &[u8]) -> Result<Suffix> {
+    decode_suffix_bytes(suffix, ParseOptions::strict())
+}
+
+const fn decode_suffix_bytes(suffix: &[u8], options: ParseOptions) -> Result<Suffix> {
     if suffix.len() != 22 {
         return Err(Error::InvalidLength {
+            component: Component::Suffix,
+            position: 0,
             expected: 22,
             found: suffix.len(),
         });
     }
 
-    suffix
-        .as_bytes()
-        .iter()
-        .enumerate()
-        .try_fold(0u128, |acc, (i, &ch)| {
-            let value = ALPHABET_INV[ch as usize];
+    let mut result = 0u128;
+    let mut i = 0;
+
+    while i < 22 {
+        let ch = match suffix[i] {
+            b'A'..=b'Z' if options.case_insensitive => suffix[i].to_ascii_lowercase(),
+            ch => ch,
+        };
+
+        let ch = match ch {
+            b'i' | b'l' if options.normalize => b'1',
+            b'o' if options.normalize => b'0',
+            ch => ch,
+        };
 
-            if value == 0xff || (i == 0 && value > 7) {
-                return Err(Error::InvalidChar { found: ch as char });
-            }
+        let value = ALPHABET_INV[ch as usize];
+
+        if value == 0xff || (i == 0 && value > 7) {
+            let hint = if value == 0xff {
+                suffix_hint(ch)
+            } else {
+                Some(Hint::FirstSuffixChar { max: '7' })
+            };
+
+            return Err(Error::InvalidChar {
+                component: Component::Suffix,
+                position: i,
+                found: char_at(suffix, i),
+                hint,
+            });
+        }
+
+        result = (result << 5) | value as u128;
+        i += 1;
+    }
 
-            Ok((acc << 5) | value as u128)
-        })
-        .map(Suffix::new)
+    Ok(Suffix::new(result))
 }
 
 #[cfg(test)]
 mod test {
+    #[cfg(feature = "alloc")]
+    use crate::encoding::encode_suffix;
     use crate::{
-        encoding::{decode_suffix, encode_suffix},
+        encoding::{decode_suffix, decode_suffix_with},
+        error::{Component, Error, Hint},
+        options::ParseOptions,
         suffix::Suffix,
     };
+    #[cfg(feature = "alloc")]
     use rand::random;
 
     #[test]
@@ -67,6 +117,52 @@ mod test {
         );
     }
 
+    #[test]
+    fn decode_lenient() {
+        let expected = decode_suffix("0123456789abcdefghjkmn");
+
+        assert_eq!(
+            expected,
+            decode_suffix_with("O123456789ABCDEFGHJKMN", ParseOptions::lenient())
+        );
+        assert_eq!(
+            expected,
+            decode_suffix_with("o123456789abcdefghjkmn", ParseOptions::lenient())
+        );
+        assert_eq!(
+            decode_suffix("0111456789abcdefghjkmn"),
+            decode_suffix_with("0iIL456789abcdefghjkmn", ParseOptions::lenient())
+        );
+        assert_eq!(
+            Err(Error::InvalidChar {
+                component: Component::Suffix,
+                position: 0,
+                found: 'o',
+                hint: Some(Hint::Ambiguous),
+            }),
+            decode_suffix_with("o123456789abcdefghjkmn", ParseOptions::strict())
+        );
+        assert_eq!(
+            Err(Error::InvalidChar {
+                component: Component::Suffix,
+                position: 0,
+                found: 'u',
+                hint: Some(Hint::Ambiguous),
+            }),
+            decode_suffix_with("u123456789abcdefghjkmn", ParseOptions::lenient())
+        );
+        assert_eq!(
+            Err(Error::InvalidChar {
+                component: Component::Suffix,
+                position: 3,
+                found: 'A',
+                hint: Some(Hint::Uppercase),
+            }),
+            decode_suffix("012A456789abcdefghjkmn")
+        );
+    }
+
+    #[cfg(feature = "alloc")]
     #[test]
     fn round_trip() {
         for _ in 0..100000 {
@@ -75,7 +171,23 @@ mod test {
             let result = encode_suffix(value.into());
             let parsed = decode_suffix(&result).unwrap();
 
-            assert_eq!(value, parsed.into());
+            assert_eq!(value, parsed.to_u128());
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn timestamp_ordering() {
+        let mut previous = encode_suffix(Suffix::from_timestamp(0, random()));
+
+        for timestamp in (1..1 << 48).step_by(0x1234_5678) {
+            let suffix = Suffix::from_timestamp(timestamp, random());
+            let current = encode_suffix(suffix);
+
+            assert_eq!(timestamp, suffix.timestamp());
+            assert!(previous < current);
+
+            previous = current;
         }
     }
 }
@@ -1,50 +1,416 @@
-use std::fmt::{Display, Formatter};
+use crate::prefix::Prefix;
+#[cfg(feature = "alloc")]
+use alloc::string::{String, ToString};
+use core::fmt::{Debug, Display, Formatter};
 
 /// A convenience type for [`Result<T, Error>`](core::result::Result)
-pub type Result<T> = std::result::Result<T, Error>;
+pub type Result<T> = core::result::Result<T, Error>;
 
 /// An enum providing all possible errors generated by this crate.
+///
+/// Positions are byte offsets into the string passed to the parsing method.
+///
+/// ```
+/// # use souvenir_core::{error::{Component, Error, Hint}, id::Id};
+/// let error = Id::parse("User_02v58c5a3fy30k560qrtg4").unwrap_err();
+///
+/// assert_eq!(Some(0), error.position());
+/// assert_eq!(Some(Component::Prefix), error.component());
+/// assert_eq!(Some(Hint::Uppercase), error.hint());
+/// assert_eq!(
+///     error.to_string(),
+///     "invalid prefix `User` at byte 0: uppercase letters are not allowed"
+/// );
+/// ```
 #[derive(Debug, Hash, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum Error {
     /// Thrown when a string with invalid data is attempted to be parsed into
     /// an identifier.
     InvalidData,
 
     /// The provided prefix contains invalid characters or is the wrong length.
-    InvalidPrefix,
+    InvalidPrefix {
+        position: usize,
+        found: Snippet,
+        hint: Hint,
+    },
 
-    /// The provided input is in an invalid format.
-    InvalidFormat,
+    /// The provided input is in an invalid format, such as when the `_`
+    /// separator is missing.
+    InvalidFormat {
+        position: usize,
+        found: Snippet,
+        hint: Hint,
+    },
 
     /// Thrown when a string containing an invalid character is attempted to
-    /// be pasrsed into an identifier.
-    InvalidChar { found: char },
+    /// be parsed into an identifier.
+    InvalidChar {
+        component: Component,
+        position: usize,
+        found: char,
+        hint: Option<Hint>,
+    },
 
     /// Thrown when a string of invalid length is attempted to be parsed into
     /// an identifier.
-    InvalidLength { expected: usize, found: usize },
+    InvalidLength {
+        component: Component,
+        position: usize,
+        expected: usize,
+        found: usize,
+    },
+
+    /// Thrown when an identifier does not have the prefix required by its
+    /// type.
+    PrefixMismatch { expected: Prefix, found: Prefix },
+
+    /// Thrown when an identifier has a prefix which is not in a
+    /// [`PrefixRegistry`](crate::registry::PrefixRegistry).
+    UnknownPrefix { found: Prefix },
+
+    /// Thrown when a generator has run out of identifiers for the current
+    /// millisecond.
+    Overflow,
+
+    /// Thrown when the check symbol of a checked identifier does not match
+    /// its contents.
+    ChecksumMismatch {
+        position: usize,
+        expected: char,
+        found: char,
+    },
 }
 
 impl Error {
     /// Get the error message
+    #[cfg(feature = "alloc")]
     pub fn message(&self) -> String {
+        self.to_string()
+    }
+
+    /// Get the byte offset in the input at which this error occurred.
+    pub const fn position(&self) -> Option<usize> {
         match self {
-            Self::InvalidData => "input contains invalid data".to_owned(),
-            Self::InvalidPrefix => "prefix is not valid".to_owned(),
-            Self::InvalidFormat => "format is not correct".to_owned(),
-            Self::InvalidChar { found } => format!("invalid character: {}", found),
-            Self::InvalidLength { expected, found } => format!(
-                "input is the wrong length: expected {} but found {}",
+            Self::InvalidPrefix { position, .. }
+            | Self::InvalidFormat { position, .. }
+            | Self::InvalidChar { position, .. }
+            | Self::InvalidLength { position, .. }
+            | Self::ChecksumMismatch { position, .. } => Some(*position),
+            _ => None,
+        }
+    }
+
+    /// Get the component of the input in which this error occurred.
+    pub const fn component(&self) -> Option<Component> {
+        match self {
+            Self::InvalidPrefix { .. } | Self::UnknownPrefix { .. } => Some(Component::Prefix),
+            Self::InvalidFormat { .. } => Some(Component::Separator),
+            Self::InvalidChar { component, .. } | Self::InvalidLength { component, .. } => {
+                Some(*component)
+            }
+            Self::ChecksumMismatch { .. } => Some(Component::Checksum),
+            _ => None,
+        }
+    }
+
+    /// Get a hint describing how to fix the input, if there is one.
+    pub const fn hint(&self) -> Option<Hint> {
+        match self {
+            Self::InvalidPrefix { hint, .. } | Self::InvalidFormat { hint, .. } => Some(*hint),
+            Self::InvalidChar { hint, .. } => *hint,
+            _ => None,
+        }
+    }
+
+    /// Move the position of this error forward by the provided number of
+    /// bytes, for when a component was parsed separately from its input.
+    pub(crate) const fn offset(self, by: usize) -> Self {
+        match self {
+            Self::InvalidPrefix {
+                position,
+                found,
+                hint,
+            } => Self::InvalidPrefix {
+                position: position + by,
+                found,
+                hint,
+            },
+            Self::InvalidFormat {
+                position,
+                found,
+                hint,
+            } => Self::InvalidFormat {
+                position: position + by,
+                found,
+                hint,
+            },
+            Self::InvalidChar {
+                component,
+                position,
+                found,
+                hint,
+            } => Self::InvalidChar {
+                component,
+                position: position + by,
+                found,
+                hint,
+            },
+            Self::InvalidLength {
+                component,
+                position,
+                expected,
+                found,
+            } => Self::InvalidLength {
+                component,
+                position: position + by,
+                expected,
+                found,
+            },
+            Self::ChecksumMismatch {
+                position,
+                expected,
+                found,
+            } => Self::ChecksumMismatch {
+                position: position + by,
+                expected,
+                found,
+            },
+            other => other,
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::InvalidData => write!(f, "input contains invalid data"),
+            Self::InvalidPrefix {
+                position,
+                found,
+                hint,
+            } => write!(f, "invalid prefix `{found}` at byte {position}: {hint}"),
+            Self::InvalidFormat {
+                position,
+                found,
+                hint,
+            } => write!(f, "invalid format `{found}` at byte {position}: {hint}"),
+            Self::InvalidChar {
+                component,
+                position,
+                found,
+                hint,
+            } => {
+                write!(
+                    f,
+                    "invalid character `{}` in {component} at byte {position}",
+                    found.escape_debug()
+                )?;
+
+                match hint {
+                    Some(hint) => write!(f, ": {hint}"),
+                    None => Ok(()),
+                }
+            }
+            Self::InvalidLength {
+                component,
+                position,
+                expected,
+                found,
+            } => write!(
+                f,
+                "{component} at byte {position} is the wrong length: expected {expected} but found {found}",
+            ),
+            Self::PrefixMismatch { expected, found } => write!(
+                f,
+                "prefix does not match: expected {} but found {}",
                 expected, found
             ),
+            Self::UnknownPrefix { found } => write!(f, "prefix {found} is not registered"),
+            Self::Overflow => write!(f, "generator has run out of identifiers"),
+            Self::ChecksumMismatch {
+                position,
+                expected,
+                found,
+            } => write!(
+                f,
+                "checksum at byte {position} does not match: expected {expected} but found {found}",
+            ),
         }
     }
 }
 
-impl Display for Error {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.message())
+impl core::error::Error for Error {}
+
+/// The part of an identifier in which an [`Error`] occurred.
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Component {
+    /// The prefix before the `_` separator.
+    Prefix,
+
+    /// The `_` separator between the prefix and the suffix.
+    Separator,
+
+    /// The suffix after the `_` separator.
+    Suffix,
+
+    /// The check symbol at the end of a checked identifier.
+    Checksum,
+}
+
+impl Display for Component {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            Self::Prefix => "prefix",
+            Self::Separator => "separator",
+            Self::Suffix => "suffix",
+            Self::Checksum => "check symbol",
+        })
     }
 }
 
-impl std::error::Error for Error {}
+/// A hint describing why an input could not be parsed.
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Hint {
+    /// The component is empty.
+    Empty,
+
+    /// The component is longer than the maximum number of characters.
+    TooLong { max: usize },
+
+    /// The character is an uppercase letter, which is only accepted by
+    /// lenient parsing.
+    Uppercase,
+
+    /// The character is a digit, which is not allowed in a
+    /// [`Prefix`](crate::prefix::Prefix).
+    Digit,
+
+    /// The first character of a prefix is not a letter.
+    LeadingDigit,
+
+    /// The input contains more than one `_` separator.
+    ExtraSeparator,
+
+    /// The input does not contain a `_` separator.
+    MissingSeparator,
+
+    /// The character is one of `i`, `l`, `o` or `u`, which are excluded from
+    /// the suffix alphabet.
+    Ambiguous,
+
+    /// The first character of the suffix is out of range, as the suffix
+    /// would not fit in its bits.
+    FirstSuffixChar { max: char },
+
+    /// The character is not allowed.
+    Unexpected { found: char },
+}
+
+impl Display for Hint {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Empty => write!(f, "must not be empty"),
+            Self::TooLong { max } => write!(f, "must be at most {max} characters long"),
+            Self::Uppercase => write!(f, "uppercase letters are not allowed"),
+            Self::Digit => write!(f, "digits are not allowed"),
+            Self::LeadingDigit => write!(f, "first character must be a letter"),
+            Self::ExtraSeparator => write!(f, "only one `_` separator is allowed"),
+            Self::MissingSeparator => write!(f, "expected a `_` separator"),
+            Self::Ambiguous => write!(f, "the characters i, l, o and u are not allowed"),
+            Self::FirstSuffixChar { max } => {
+                write!(f, "first suffix character must be 0-{max}")
+            }
+            Self::Unexpected { found } => {
+                write!(f, "unexpected character `{}`", found.escape_debug())
+            }
+        }
+    }
+}
+
+/// A short excerpt of the input which caused an [`Error`], stored inline
+/// without any heap allocation. Inputs longer than
+/// [`Snippet::MAX_LENGTH`] bytes are truncated.
+#[derive(Copy, Clone, Hash, PartialEq, Eq)]
+pub struct Snippet {
+    buf: [u8; Snippet::MAX_LENGTH],
+    len: u8,
+}
+
+impl Snippet {
+    /// The maximum number of bytes stored in a [`Snippet`].
+    pub const MAX_LENGTH: usize = 32;
+
+    /// Create a [`Snippet`] from the start of the provided string.
+    pub const fn new(value: &str) -> Self {
+        Self::from_bytes(value.as_bytes())
+    }
+
+    /// Create a [`Snippet`] from the start of the provided bytes, which must
+    /// be valid UTF-8.
+    pub(crate) const fn from_bytes(value: &[u8]) -> Self {
+        let mut len = if value.len() > Self::MAX_LENGTH {
+            Self::MAX_LENGTH
+        } else {
+            value.len()
+        };
+
+        // Don't split a multi-byte character.
+        while len < value.len() && len > 0 && value[len] & 0xc0 == 0x80 {
+            len -= 1;
+        }
+
+        let mut buf = [0; Self::MAX_LENGTH];
+        let mut i = 0;
+
+        while i < len {
+            buf[i] = value[i];
+            i += 1;
+        }
+
+        Self {
+            buf,
+            len: len as u8,
+        }
+    }
+
+    /// Get the string slice.
+    pub fn as_str(&self) -> &str {
+        // UNSAFE: The buffer is copied from a string, and is only truncated
+        // at character boundaries.
+        unsafe { core::str::from_utf8_unchecked(&self.buf[..self.len as usize]) }
+    }
+}
+
+impl AsRef<str> for Snippet {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl PartialEq<str> for Snippet {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for Snippet {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl Debug for Snippet {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl Display for Snippet {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
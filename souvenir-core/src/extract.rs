@@ -0,0 +1,84 @@
+use crate::error::Error;
+use crate::id::Id;
+use alloc::string::{String, ToString};
+use serde_json::{Value, json};
+
+/// An extractor which parses an identifier from a single path parameter.
+///
+/// `T` can be any identifier which implements [`FromStr`](core::str::FromStr)
+/// with an [`Error`], such as an [`Id`], a
+/// [`TypedId`](crate::typed::TypedId) to enforce a specific prefix, or a
+/// [`KnownId`](crate::registry::KnownId) to enforce a registered prefix.
+///
+/// If the parameter is not a valid identifier, the request is rejected with a
+/// `400 Bad Request`. If the identifier is valid but has the wrong prefix,
+/// the request is rejected with a `404 Not Found`, as no resource can exist
+/// at that path. In both cases, the body is a JSON object such as:
+///
+/// ```json
+/// {
+///     "error": "invalid_prefix",
+///     "message": "invalid prefix `User` at byte 0: uppercase letters are not allowed",
+///     "component": "prefix",
+///     "position": 0,
+///     "hint": "uppercase letters are not allowed"
+/// }
+/// ```
+///
+/// If the route does not have exactly one path parameter, the request is
+/// rejected with the status code of the framework and a body with an
+/// `"invalid_path"` error and a message.
+#[derive(Copy, Clone, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct IdPath<T = Id>(pub T);
+
+/// An extractor which deserializes query parameters containing identifiers.
+///
+/// This behaves like the query extractor of the framework, except that the
+/// request is rejected with a `400 Bad Request` and a JSON body with an
+/// `"invalid_query"` error and a message when the query parameters are not
+/// valid, including when an identifier in them is not valid.
+#[derive(Copy, Clone, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct IdQuery<T>(pub T);
+
+/// Get the HTTP status code used when an [`Error`] is returned from a
+/// request handler.
+pub(crate) fn status(error: &Error) -> u16 {
+    match error {
+        Error::PrefixMismatch { .. } | Error::UnknownPrefix { .. } => 404,
+        Error::Overflow => 503,
+        _ => 400,
+    }
+}
+
+/// Get the JSON body used when an [`Error`] is returned from a request
+/// handler.
+pub(crate) fn body(error: &Error) -> Value {
+    let code = match error {
+        Error::InvalidData => "invalid_data",
+        Error::InvalidPrefix { .. } => "invalid_prefix",
+        Error::InvalidFormat { .. } => "invalid_format",
+        Error::InvalidChar { .. } => "invalid_char",
+        Error::InvalidLength { .. } => "invalid_length",
+        Error::PrefixMismatch { .. } => "prefix_mismatch",
+        Error::UnknownPrefix { .. } => "unknown_prefix",
+        Error::Overflow => "overflow",
+        Error::ChecksumMismatch { .. } => "checksum_mismatch",
+    };
+
+    json!({
+        "error": code,
+        "message": error.to_string(),
+        "component": error.component().map(|component| component.to_string()),
+        "position": error.position(),
+        "hint": error.hint().map(|hint| hint.to_string()),
+    })
+}
+
+/// Get the JSON body used when the framework rejects a request for a reason
+/// other than an invalid identifier.
+pub(crate) fn rejection_body(code: &str, message: String) -> Value {
+    json!({
+        "error": code,
+        "message": message,
+    })
+}
@@ -0,0 +1,345 @@
+use crate::clock::{Clock, SystemClock};
+use crate::error::{Error, Result};
+use crate::id::Id;
+use crate::prefix::Prefix;
+use crate::suffix::Suffix;
+use crate::tagged::Tagged;
+use crate::typed::TypedId;
+use rand::{Rng, RngCore, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use std::sync::{Mutex, PoisonError};
+
+/// An RNG which draws from the thread-local RNG provided by [`rand::rng`].
+#[derive(Copy, Clone, Debug, Default, Hash, PartialEq, Eq)]
+pub struct SystemRng;
+
+impl RngCore for SystemRng {
+    fn next_u32(&mut self) -> u32 {
+        rand::rng().next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        rand::rng().next_u64()
+    }
+
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        rand::rng().fill_bytes(dst)
+    }
+}
+
+#[derive(Debug)]
+struct State<R> {
+    last: Suffix,
+    rng: R,
+}
+
+/// A thread-safe generator of time-ordered identifiers which are strictly
+/// increasing within one process.
+///
+/// When the clock advances, a new random suffix is chosen for the current
+/// millisecond. Otherwise, including when the clock goes backwards, the
+/// previous suffix is incremented, keeping its timestamp. Since the highest
+/// random bit is cleared at the start of each millisecond, at least 2^59
+/// identifiers can be generated per millisecond before
+/// [`Error::Overflow`] is returned.
+///
+/// ```
+/// # use souvenir_core::{generator::IdGenerator, prefix::Prefix};
+/// static USERS: IdGenerator = IdGenerator::new(unsafe {
+///     Prefix::new_unchecked(0b10101_10011_00101_10010)
+/// });
+///
+/// let a = USERS.generate().unwrap();
+/// let b = USERS.generate().unwrap();
+///
+/// assert!(a < b);
+/// assert!(a.to_string() < b.to_string());
+/// ```
+///
+/// The clock and RNG can be replaced using [`IdGenerator::builder`], which is
+/// useful to generate reproducible identifiers in tests.
+///
+/// ```
+/// # use souvenir_core::{clock::FixedClock, generator::IdGenerator, prefix::Prefix};
+/// let prefix: Prefix = "user".parse().unwrap();
+///
+/// let generator = IdGenerator::builder(prefix)
+///     .clock(FixedClock::new(1_700_000_000_000))
+///     .seed(42)
+///     .build();
+///
+/// let again = IdGenerator::builder(prefix)
+///     .clock(FixedClock::new(1_700_000_000_000))
+///     .seed(42)
+///     .build();
+///
+/// assert_eq!(generator.generate().unwrap(), again.generate().unwrap());
+/// ```
+#[derive(Debug)]
+pub struct IdGenerator<C = SystemClock, R = SystemRng> {
+    prefix: Prefix,
+    clock: C,
+    state: Mutex<State<R>>,
+}
+
+impl IdGenerator {
+    /// Create a new [`IdGenerator`] for the provided prefix.
+    pub const fn new(prefix: Prefix) -> Self {
+        Self::with(prefix, SystemClock, SystemRng)
+    }
+
+    /// Create a new [`IdGenerator`] for the prefix of a [`Tagged`] type.
+    pub const fn tagged<T: Tagged>() -> Self {
+        Self::new(T::PREFIX)
+    }
+
+    /// Create an [`IdGeneratorBuilder`] for the provided prefix.
+    pub const fn builder(prefix: Prefix) -> IdGeneratorBuilder {
+        IdGeneratorBuilder {
+            prefix,
+            clock: SystemClock,
+            rng: SystemRng,
+        }
+    }
+}
+
+impl IdGenerator<SystemClock, ChaCha8Rng> {
+    /// Create a new [`IdGenerator`] for the provided prefix, with an RNG
+    /// seeded from the provided value.
+    pub fn seeded(prefix: Prefix, seed: u64) -> Self {
+        IdGenerator::builder(prefix).seed(seed).build()
+    }
+}
+
+impl<C: Clock, R: RngCore> IdGenerator<C, R> {
+    /// Create a new [`IdGenerator`] with the provided prefix, clock and RNG.
+    pub const fn with(prefix: Prefix, clock: C, rng: R) -> Self {
+        Self {
+            prefix,
+            clock,
+            state: Mutex::new(State {
+                last: Suffix::new(0),
+                rng,
+            }),
+        }
+    }
+
+    /// Get the prefix of the identifiers generated by this [`IdGenerator`].
+    pub const fn prefix(&self) -> Prefix {
+        self.prefix
+    }
+
+    /// Get the clock used by this [`IdGenerator`].
+    pub const fn clock(&self) -> &C {
+        &self.clock
+    }
+
+    /// Generate the next [`Id`].
+    pub fn generate(&self) -> Result<Id> {
+        let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        let State { last, rng } = &mut *state;
+
+        self.advance(last, rng)
+    }
+
+    /// Generate the next [`Id`] with the provided RNG instead of the RNG of
+    /// this [`IdGenerator`].
+    pub fn generate_with<G: Rng + ?Sized>(&self, rng: &mut G) -> Result<Id> {
+        let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+
+        self.advance(&mut state.last, rng)
+    }
+
+    /// Generate the next [`TypedId`]. If the prefix of this [`IdGenerator`]
+    /// does not match `T`, this will error.
+    pub fn generate_typed<T: Tagged>(&self) -> Result<TypedId<T>> {
+        self.generate().and_then(TypedId::from_id)
+    }
+
+    fn advance<G: Rng + ?Sized>(&self, last: &mut Suffix, rng: &mut G) -> Result<Id> {
+        let timestamp = self.clock.now();
+
+        let next = if timestamp > last.timestamp() {
+            let random: u64 = rng.random();
+            Suffix::from_timestamp(timestamp, random >> (64 - Suffix::RANDOM_BITS + 1))
+        } else {
+            let mask = (1 << Suffix::RANDOM_BITS) - 1;
+
+            if last.to_u128() & mask == mask {
+                return Err(Error::Overflow);
+            }
+
+            Suffix::new(last.to_u128() + 1)
+        };
+
+        *last = next;
+        Ok(Id::new(self.prefix, next))
+    }
+}
+
+/// A builder for an [`IdGenerator`] with a custom [`Clock`] and RNG.
+#[derive(Debug)]
+pub struct IdGeneratorBuilder<C = SystemClock, R = SystemRng> {
+    prefix: Prefix,
+    clock: C,
+    rng: R,
+}
+
+impl<C: Clock, R: RngCore> IdGeneratorBuilder<C, R> {
+    /// Use the provided [`Clock`].
+    pub fn clock<D: Clock>(self, clock: D) -> IdGeneratorBuilder<D, R> {
+        IdGeneratorBuilder {
+            prefix: self.prefix,
+            clock,
+            rng: self.rng,
+        }
+    }
+
+    /// Use the provided RNG.
+    pub fn rng<G: RngCore>(self, rng: G) -> IdGeneratorBuilder<C, G> {
+        IdGeneratorBuilder {
+            prefix: self.prefix,
+            clock: self.clock,
+            rng,
+        }
+    }
+
+    /// Use a [`ChaCha8Rng`] seeded from the provided value. Unlike
+    /// [`StdRng`](rand::rngs::StdRng), its output is portable and will not
+    /// change between releases, so seeded identifiers are stable.
+    pub fn seed(self, seed: u64) -> IdGeneratorBuilder<C, ChaCha8Rng> {
+        self.rng(ChaCha8Rng::seed_from_u64(seed))
+    }
+
+    /// Build the [`IdGenerator`].
+    pub fn build(self) -> IdGenerator<C, R> {
+        IdGenerator::with(self.prefix, self.clock, self.rng)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        clock::{Clock, FixedClock, SteppingClock, SystemClock},
+        error::Error,
+        generator::IdGenerator,
+        prefix::Prefix,
+        suffix::Suffix,
+    };
+    use std::{sync::Arc, thread};
+
+    const PREFIX: Prefix = unsafe { Prefix::new_unchecked(0b10101_10011_00101_10010) };
+
+    #[test]
+    fn strictly_increasing() {
+        let generator = IdGenerator::new(PREFIX);
+        let mut previous = generator.generate().unwrap();
+
+        for _ in 0..100000 {
+            let current = generator.generate().unwrap();
+
+            assert!(previous < current);
+            assert!(previous.to_string() < current.to_string());
+
+            previous = current;
+        }
+    }
+
+    #[test]
+    fn strictly_increasing_threaded() {
+        let generator = Arc::new(IdGenerator::new(PREFIX));
+
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let generator = generator.clone();
+                thread::spawn(move || {
+                    (0..10000)
+                        .map(|_| generator.generate().unwrap())
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect();
+
+        let mut ids: Vec<_> = handles
+            .into_iter()
+            .flat_map(|handle| handle.join().unwrap())
+            .collect();
+
+        ids.sort();
+        ids.dedup();
+
+        assert_eq!(40000, ids.len());
+    }
+
+    #[test]
+    fn clock_backwards() {
+        let clock = SteppingClock::new(1000, u64::MAX);
+        let generator = IdGenerator::builder(PREFIX).clock(clock).build();
+
+        let first = generator.generate().unwrap();
+        let second = generator.generate().unwrap();
+
+        assert_eq!(1000, second.timestamp());
+        assert_eq!(first.suffix().to_u128() + 1, second.suffix().to_u128());
+    }
+
+    #[test]
+    fn overflow() {
+        let future = SystemClock.now() + 60_000;
+        let generator = IdGenerator::new(PREFIX);
+
+        generator.state.lock().unwrap().last = Suffix::from_timestamp(future, u64::MAX);
+
+        assert_eq!(Err(Error::Overflow), generator.generate());
+    }
+
+    #[test]
+    fn deterministic() {
+        let build = || {
+            IdGenerator::builder(PREFIX)
+                .clock(SteppingClock::new(1_700_000_000_000, 1))
+                .seed(42)
+                .build()
+        };
+
+        let a = build();
+        let b = build();
+
+        for _ in 0..100 {
+            assert_eq!(a.generate(), b.generate());
+        }
+
+        let fixed = IdGenerator::builder(PREFIX)
+            .clock(FixedClock::new(1_700_000_000_000))
+            .seed(42)
+            .build();
+
+        let first = fixed.generate().unwrap();
+        let second = fixed.generate().unwrap();
+
+        assert_eq!(1_700_000_000_000, first.timestamp());
+        assert_eq!(first.suffix().to_u128() + 1, second.suffix().to_u128());
+    }
+
+    #[test]
+    fn seeded_snapshot() {
+        let prefix = Prefix::parse("user").unwrap();
+        let fixed = IdGenerator::builder(prefix)
+            .clock(FixedClock::new(1_700_000_000_000))
+            .seed(42)
+            .build()
+            .generate()
+            .unwrap();
+
+        assert_eq!("user_01hf7yat00ax45zpmwntpx", fixed.to_string());
+
+        // `seeded` uses the system clock, but draws the same random bits
+        let seeded = IdGenerator::seeded(prefix, 42).generate().unwrap();
+        let mask = (1 << Suffix::RANDOM_BITS) - 1;
+
+        assert_eq!(
+            fixed.suffix().to_u128() & mask,
+            seeded.suffix().to_u128() & mask
+        );
+    }
+}
@@ -1,9 +1,13 @@
-use crate::encoding::{decode_id, encode_id, validate_id};
+use crate::encoding::{decode_id, decode_id_checked, decode_id_const, decode_id_with, validate_id};
 use crate::error::{Error, Result};
+use crate::options::ParseOptions;
 use crate::prefix::Prefix;
+use crate::string::IdString;
 use crate::suffix::Suffix;
-use std::fmt::{Debug, Display, Formatter};
-use std::str::FromStr;
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+use core::fmt::{Debug, Display, Formatter};
+use core::str::FromStr;
 
 /// Type of the underlying data stored in an [`Id`], which is an array of
 /// 16 bytes. Note that not all possible values represent valid identifiers.
@@ -13,8 +17,10 @@ pub type IdBytes = [u8; 16];
 ///
 /// ```
 /// # use souvenir_core::id::Id;
+/// # #[cfg(all(feature = "rand", feature = "std"))] {
 /// let id: Id = Id::random("user".parse().unwrap());
 /// println!("{}", id);
+/// # }
 ///
 /// let id2: Id = Id::parse("user_02v58c5a3fy30k560qrtg4").unwrap();
 /// assert_eq!(id2.to_string(), "user_02v58c5a3fy30k560qrtg4");
@@ -24,9 +30,18 @@ pub type IdBytes = [u8; 16];
     derive(::diesel::AsExpression, ::diesel::FromSqlRow)
 )]
 #[cfg_attr(all(feature = "diesel", feature = "postgres"), diesel(sql_type = ::diesel::sql_types::Uuid))]
-#[cfg_attr(all(feature = "diesel", feature = "mysql"), diesel(sql_type = ::diesel::sql_types::Binary))]
+#[cfg_attr(all(feature = "diesel", any(feature = "mysql", feature = "sqlite")), diesel(sql_type = ::diesel::sql_types::Binary))]
 #[cfg_attr(all(feature = "diesel", feature = "sqlite"), diesel(sql_type = ::diesel::sql_types::Text))]
 #[derive(Copy, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        ::zerocopy::Immutable,
+        ::zerocopy::IntoBytes,
+        ::zerocopy::KnownLayout,
+        ::zerocopy::Unaligned
+    )
+)]
 #[repr(transparent)]
 pub struct Id(IdBytes);
 
@@ -41,7 +56,7 @@ impl Id {
 
     /// Create a new [`Id`] with the following bytes. If the provided bytes do
     /// not form a valid [`Id`], this method will error.
-    pub fn from_bytes(value: [u8; 16]) -> Result<Self> {
+    pub const fn from_bytes(value: [u8; 16]) -> Result<Self> {
         validate_id(value)
     }
 
@@ -85,6 +100,14 @@ impl Id {
         Suffix::new(self.to_u128())
     }
 
+    /// Get the millisecond Unix timestamp stored in the suffix of this
+    /// identifier.
+    ///
+    /// This is only meaningful for time-ordered identifiers.
+    pub const fn timestamp(self) -> u64 {
+        self.suffix().timestamp()
+    }
+
     /// Cast this [`Id`] into an [`Id`] with a different prefix.
     pub const fn cast(self, prefix: Prefix) -> Self {
         Self::new(prefix, Suffix::new(u128::from_be_bytes(self.0)))
@@ -99,17 +122,117 @@ impl Id {
     pub fn parse(value: &str) -> Result<Self> {
         decode_id(value)
     }
+
+    /// Attempt to parse the provided string into an [`Id`] using the
+    /// provided [`ParseOptions`].
+    pub fn parse_with(value: &str, options: ParseOptions) -> Result<Self> {
+        decode_id_with(value, options)
+    }
+
+    /// Attempt to parse the provided string into an [`Id`], accepting
+    /// uppercase characters and normalizing ambiguous characters.
+    /// See [`ParseOptions::lenient`].
+    pub fn parse_lenient(value: &str) -> Result<Self> {
+        Self::parse_with(value, ParseOptions::lenient())
+    }
+
+    /// Parse the provided string into an [`Id`] in a `const` context. Only
+    /// the strict format is accepted.
+    ///
+    /// # Panics
+    /// Panics if the string is not a valid [`Id`]. When evaluated in a
+    /// `const` item, this results in a compile error.
+    ///
+    /// ```
+    /// # use souvenir_core::id::Id;
+    /// const ADMIN: Id = Id::from_str_const("user_02v58c5a3fy30k560qrtg4");
+    ///
+    /// assert_eq!(ADMIN.to_string(), "user_02v58c5a3fy30k560qrtg4");
+    /// ```
+    ///
+    /// ```compile_fail
+    /// # use souvenir_core::id::Id;
+    /// const ADMIN: Id = Id::from_str_const("user_02v58c5a3fy30k560qrtgu");
+    /// ```
+    pub const fn from_str_const(value: &str) -> Self {
+        match decode_id_const(value.as_bytes()) {
+            Ok(id) => id,
+            Err(Error::InvalidFormat { .. }) => panic!("invalid id: missing `_` separator"),
+            Err(Error::InvalidPrefix { .. }) => panic!("invalid id: invalid prefix"),
+            Err(Error::InvalidLength { .. }) => {
+                panic!("invalid id: suffix must be 22 characters long")
+            }
+            Err(_) => panic!("invalid id: invalid character in suffix"),
+        }
+    }
+
+    /// Get the string representation of this [`Id`] without allocating.
+    pub fn encode(self) -> IdString {
+        IdString::new(self)
+    }
+
+    /// Write the string representation of this [`Id`] into the provided
+    /// buffer, returning the written part as a string slice.
+    ///
+    /// # Panics
+    /// Panics if the buffer is too short to contain the string. A buffer of
+    /// [`IdString::MAX_LENGTH`] bytes is always large enough.
+    ///
+    /// ```
+    /// # use souvenir_core::id::Id;
+    /// let id = Id::parse("user_02v58c5a3fy30k560qrtg4").unwrap();
+    ///
+    /// let mut buf = [0; 32];
+    /// assert_eq!(id.encode_into(&mut buf), "user_02v58c5a3fy30k560qrtg4");
+    /// ```
+    pub fn encode_into(self, buf: &mut [u8]) -> &str {
+        let encoded = self.encode();
+        let len = encoded.len();
+
+        assert!(
+            buf.len() >= len,
+            "buffer of length {} is too short to encode an id of length {}",
+            buf.len(),
+            len
+        );
+
+        buf[..len].copy_from_slice(encoded.as_bytes());
+
+        // UNSAFE: All bytes are guaranteed to be in ASCII range.
+        unsafe { core::str::from_utf8_unchecked(&buf[..len]) }
+    }
+
+    /// Get the string representation of this [`Id`], followed by a
+    /// [Crockford](https://www.crockford.com/base32.html) mod 37 check
+    /// symbol, which detects single character typos and adjacent
+    /// transpositions.
+    ///
+    /// ```
+    /// # use souvenir_core::id::Id;
+    /// let id = Id::parse("user_02v58c5a3fy30k560qrtg4").unwrap();
+    /// assert_eq!(id.to_checked_string(), "user_02v58c5a3fy30k560qrtg44");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn to_checked_string(self) -> String {
+        crate::encoding::encode_id_checked(self)
+    }
+
+    /// Attempt to parse the provided string, which must end with a check
+    /// symbol, into an [`Id`]. See [`Id::to_checked_string`].
+    pub fn parse_checked(value: &str) -> Result<Self> {
+        decode_id_checked(value)
+    }
 }
 
 impl Debug for Id {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         write!(f, "{self}")
     }
 }
 
 impl Display for Id {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", encode_id(*self))
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.pad(&self.encode())
     }
 }
 
@@ -0,0 +1,214 @@
+use crate::error::Error;
+use crate::extract::{IdPath, IdQuery, body, rejection_body, status};
+use actix_web::dev::Payload;
+use actix_web::error::InternalError;
+use actix_web::http::StatusCode;
+use actix_web::web::{Path, Query};
+use actix_web::{FromRequest, HttpRequest, HttpResponse, ResponseError};
+use alloc::string::{String, ToString};
+use core::future::{Ready, ready};
+use core::str::FromStr;
+use serde::de::DeserializeOwned;
+
+impl ResponseError for Error {
+    fn status_code(&self) -> StatusCode {
+        StatusCode::from_u16(status(self)).unwrap_or(StatusCode::BAD_REQUEST)
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        HttpResponse::build(self.status_code()).json(body(self))
+    }
+}
+
+impl<T: FromStr<Err = Error>> FromRequest for IdPath<T> {
+    type Error = actix_web::Error;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, _: &mut Payload) -> Self::Future {
+        ready(
+            Path::<String>::extract(req)
+                .into_inner()
+                .map_err(|error| {
+                    let status = error.as_response_error().status_code();
+                    let body = rejection_body("invalid_path", error.to_string());
+                    let response = HttpResponse::build(status).json(body);
+
+                    InternalError::from_response(error, response).into()
+                })
+                .and_then(|value| value.parse().map(Self).map_err(Into::into)),
+        )
+    }
+}
+
+impl<T: DeserializeOwned> FromRequest for IdQuery<T> {
+    type Error = actix_web::Error;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, _: &mut Payload) -> Self::Future {
+        ready(
+            Query::<T>::from_query(req.query_string())
+                .map(|Query(value)| Self(value))
+                .map_err(|error| {
+                    let body = rejection_body("invalid_query", error.to_string());
+                    let response = HttpResponse::BadRequest().json(body);
+
+                    InternalError::from_response(error, response).into()
+                }),
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        extract::{IdPath, IdQuery},
+        id::Id,
+        prefix::Prefix,
+        tagged::Tagged,
+        typed::TypedId,
+    };
+    use actix_web::{App, http::StatusCode, rt::System, test, web};
+    use serde_json::Value;
+    use std::collections::HashMap;
+
+    struct User;
+
+    impl Tagged for User {
+        const PREFIX: Prefix = Prefix::from_str_const("user");
+    }
+
+    type Params = HashMap<String, Id>;
+
+    async fn request(uri: &str) -> (StatusCode, Vec<u8>) {
+        let app = test::init_service(
+            App::new()
+                .route(
+                    "/ids/{id}",
+                    web::get().to(|IdPath(id): IdPath| async move { id.to_string() }),
+                )
+                .route(
+                    "/users/{id}",
+                    web::get()
+                        .to(|IdPath(id): IdPath<TypedId<User>>| async move { id.to_string() }),
+                )
+                .route(
+                    "/search",
+                    web::get().to(|IdQuery(params): IdQuery<Params>| async move {
+                        params["id"].to_string()
+                    }),
+                )
+                .route(
+                    "/search/users",
+                    web::get().to(
+                        |IdQuery(params): IdQuery<HashMap<String, TypedId<User>>>| async move {
+                            params["id"].to_string()
+                        },
+                    ),
+                )
+                .route(
+                    "/count",
+                    web::get().to(
+                        |IdQuery(params): IdQuery<HashMap<String, u32>>| async move {
+                            params["n"].to_string()
+                        },
+                    ),
+                )
+                .route(
+                    "/missing",
+                    web::get().to(|IdPath(id): IdPath| async move { id.to_string() }),
+                ),
+        )
+        .await;
+
+        let response =
+            test::call_service(&app, test::TestRequest::get().uri(uri).to_request()).await;
+        let status = response.status();
+
+        (status, test::read_body(response).await.to_vec())
+    }
+
+    #[test]
+    fn path() {
+        System::new().block_on(async {
+            let (status, body) = request("/ids/user_02v58c5a3fy30k560qrtg4").await;
+            assert_eq!(StatusCode::OK, status);
+            assert_eq!(b"user_02v58c5a3fy30k560qrtg4", &*body);
+
+            let (status, body) = request("/ids/user_82v58c5a3fy30k560qrtg4").await;
+            let body: Value = serde_json::from_slice(&body).unwrap();
+
+            assert_eq!(StatusCode::BAD_REQUEST, status);
+            assert_eq!("invalid_char", body["error"]);
+            assert_eq!("suffix", body["component"]);
+            assert_eq!(5, body["position"]);
+            assert_eq!("first suffix character must be 0-7", body["hint"]);
+        });
+    }
+
+    #[test]
+    fn path_typed() {
+        System::new().block_on(async {
+            let (status, _) = request("/users/user_02v58c5a3fy30k560qrtg4").await;
+            assert_eq!(StatusCode::OK, status);
+
+            let (status, body) = request("/users/org_02v58c5a3fy30k560qrtg4").await;
+            let body: Value = serde_json::from_slice(&body).unwrap();
+
+            assert_eq!(StatusCode::NOT_FOUND, status);
+            assert_eq!("prefix_mismatch", body["error"]);
+        });
+    }
+
+    #[test]
+    fn query() {
+        System::new().block_on(async {
+            let (status, body) = request("/search?id=user_02v58c5a3fy30k560qrtg4").await;
+            assert_eq!(StatusCode::OK, status);
+            assert_eq!(b"user_02v58c5a3fy30k560qrtg4", &*body);
+
+            let (status, body) = request("/search?id=nope").await;
+            let body: Value = serde_json::from_slice(&body).unwrap();
+
+            assert_eq!(StatusCode::BAD_REQUEST, status);
+            assert_eq!("invalid_query", body["error"]);
+            assert!(body["message"].as_str().unwrap().contains("`nope`"));
+
+            let (status, body) = request("/count?n=many").await;
+            let body: Value = serde_json::from_slice(&body).unwrap();
+
+            assert_eq!(StatusCode::BAD_REQUEST, status);
+            assert_eq!("invalid_query", body["error"]);
+        });
+    }
+
+    #[test]
+    fn query_typed() {
+        System::new().block_on(async {
+            let (status, _) = request("/search/users?id=user_02v58c5a3fy30k560qrtg4").await;
+            assert_eq!(StatusCode::OK, status);
+
+            let (status, body) = request("/search/users?id=org_02v58c5a3fy30k560qrtg4").await;
+            let body: Value = serde_json::from_slice(&body).unwrap();
+
+            assert_eq!(StatusCode::BAD_REQUEST, status);
+            assert_eq!("invalid_query", body["error"]);
+            assert!(
+                body["message"]
+                    .as_str()
+                    .unwrap()
+                    .contains("prefix does not match")
+            );
+        });
+    }
+
+    #[test]
+    fn path_missing() {
+        System::new().block_on(async {
+            let (status, body) = request("/missing").await;
+            let body: Value = serde_json::from_slice(&body).unwrap();
+
+            assert_eq!(StatusCode::NOT_FOUND, status);
+            assert_eq!("invalid_path", body["error"]);
+        });
+    }
+}
@@ -0,0 +1,198 @@
+use crate::error::Error;
+use crate::extract::{IdPath, IdQuery, body, rejection_body, status};
+use alloc::string::String;
+use axum::Json;
+use axum::extract::{FromRequestParts, Path, Query};
+use axum::http::StatusCode;
+use axum::http::request::Parts;
+use axum::response::{IntoResponse, Response};
+use core::str::FromStr;
+use serde::de::DeserializeOwned;
+
+impl IntoResponse for Error {
+    fn into_response(self) -> Response {
+        let status = StatusCode::from_u16(status(&self)).unwrap_or(StatusCode::BAD_REQUEST);
+        (status, Json(body(&self))).into_response()
+    }
+}
+
+impl<S, T> FromRequestParts<S> for IdPath<T>
+where
+    S: Send + Sync,
+    T: FromStr<Err = Error>,
+{
+    type Rejection = Response;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let Path(value) = Path::<String>::from_request_parts(parts, state)
+            .await
+            .map_err(|rejection| {
+                let body = rejection_body("invalid_path", rejection.body_text());
+                (rejection.status(), Json(body)).into_response()
+            })?;
+
+        value.parse().map(Self).map_err(IntoResponse::into_response)
+    }
+}
+
+impl<S, T> FromRequestParts<S> for IdQuery<T>
+where
+    S: Send + Sync,
+    T: DeserializeOwned,
+{
+    type Rejection = Response;
+
+    async fn from_request_parts(parts: &mut Parts, _: &S) -> Result<Self, Self::Rejection> {
+        Query::<T>::try_from_uri(&parts.uri)
+            .map(|Query(value)| Self(value))
+            .map_err(|rejection| {
+                let body = rejection_body("invalid_query", rejection.body_text());
+                (StatusCode::BAD_REQUEST, Json(body)).into_response()
+            })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        extract::{IdPath, IdQuery},
+        id::Id,
+        prefix::Prefix,
+        tagged::Tagged,
+        typed::TypedId,
+    };
+    use axum::{Router, body::Body, http::Request, http::StatusCode, routing::get};
+    use serde_json::Value;
+    use std::collections::HashMap;
+    use tower::ServiceExt;
+
+    struct User;
+
+    impl Tagged for User {
+        const PREFIX: Prefix = Prefix::from_str_const("user");
+    }
+
+    type Params = HashMap<String, Id>;
+
+    fn router() -> Router {
+        Router::new()
+            .route(
+                "/ids/{id}",
+                get(|IdPath(id): IdPath| async move { id.to_string() }),
+            )
+            .route(
+                "/users/{id}",
+                get(|IdPath(id): IdPath<TypedId<User>>| async move { id.to_string() }),
+            )
+            .route(
+                "/search",
+                get(|IdQuery(params): IdQuery<Params>| async move { params["id"].to_string() }),
+            )
+            .route(
+                "/search/users",
+                get(
+                    |IdQuery(params): IdQuery<HashMap<String, TypedId<User>>>| async move {
+                        params["id"].to_string()
+                    },
+                ),
+            )
+            .route(
+                "/count",
+                get(
+                    |IdQuery(params): IdQuery<HashMap<String, u32>>| async move {
+                        params["n"].to_string()
+                    },
+                ),
+            )
+            .route(
+                "/missing",
+                get(|IdPath(id): IdPath| async move { id.to_string() }),
+            )
+    }
+
+    async fn request(uri: &str) -> (StatusCode, Vec<u8>) {
+        let request = Request::get(uri).body(Body::empty()).unwrap();
+        let response = router().oneshot(request).await.unwrap();
+        let status = response.status();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+
+        (status, body.to_vec())
+    }
+
+    #[tokio::test]
+    async fn path() {
+        let (status, body) = request("/ids/user_02v58c5a3fy30k560qrtg4").await;
+        assert_eq!(StatusCode::OK, status);
+        assert_eq!(b"user_02v58c5a3fy30k560qrtg4", &*body);
+
+        let (status, body) = request("/ids/User_02v58c5a3fy30k560qrtg4").await;
+        let body: Value = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(StatusCode::BAD_REQUEST, status);
+        assert_eq!("invalid_prefix", body["error"]);
+        assert_eq!("prefix", body["component"]);
+        assert_eq!(0, body["position"]);
+        assert_eq!("uppercase letters are not allowed", body["hint"]);
+    }
+
+    #[tokio::test]
+    async fn path_typed() {
+        let (status, _) = request("/users/user_02v58c5a3fy30k560qrtg4").await;
+        assert_eq!(StatusCode::OK, status);
+
+        let (status, body) = request("/users/org_02v58c5a3fy30k560qrtg4").await;
+        let body: Value = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(StatusCode::NOT_FOUND, status);
+        assert_eq!("prefix_mismatch", body["error"]);
+    }
+
+    #[tokio::test]
+    async fn query() {
+        let (status, body) = request("/search?id=user_02v58c5a3fy30k560qrtg4").await;
+        assert_eq!(StatusCode::OK, status);
+        assert_eq!(b"user_02v58c5a3fy30k560qrtg4", &*body);
+
+        let (status, body) = request("/search?id=user_02v58c5a3fy30k560qrtgu").await;
+        let body: Value = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(StatusCode::BAD_REQUEST, status);
+        assert_eq!("invalid_query", body["error"]);
+        assert!(body["message"].as_str().unwrap().contains("at byte 26"));
+
+        let (status, body) = request("/count?n=many").await;
+        let body: Value = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(StatusCode::BAD_REQUEST, status);
+        assert_eq!("invalid_query", body["error"]);
+    }
+
+    #[tokio::test]
+    async fn query_typed() {
+        let (status, _) = request("/search/users?id=user_02v58c5a3fy30k560qrtg4").await;
+        assert_eq!(StatusCode::OK, status);
+
+        let (status, body) = request("/search/users?id=org_02v58c5a3fy30k560qrtg4").await;
+        let body: Value = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(StatusCode::BAD_REQUEST, status);
+        assert_eq!("invalid_query", body["error"]);
+        assert!(
+            body["message"]
+                .as_str()
+                .unwrap()
+                .contains("prefix does not match")
+        );
+    }
+
+    #[tokio::test]
+    async fn path_missing() {
+        let (status, body) = request("/missing").await;
+        let body: Value = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(StatusCode::INTERNAL_SERVER_ERROR, status);
+        assert_eq!("invalid_path", body["error"]);
+    }
+}
@@ -0,0 +1,93 @@
+use crate::{
+    id::{Id, IdBytes},
+    tagged::Tagged,
+    typed::TypedId,
+};
+use alloc::string::ToString;
+use bincode::de::{BorrowDecoder, Decoder};
+use bincode::enc::{Encoder, write::Writer};
+use bincode::error::{DecodeError, EncodeError};
+use bincode::{BorrowDecode, Decode, Encode};
+
+impl Encode for Id {
+    fn encode<E: Encoder>(&self, encoder: &mut E) -> Result<(), EncodeError> {
+        encoder.writer().write(self.as_bytes())
+    }
+}
+
+impl<Context> Decode<Context> for Id {
+    fn decode<D: Decoder<Context = Context>>(decoder: &mut D) -> Result<Self, DecodeError> {
+        Self::from_bytes(IdBytes::decode(decoder)?)
+            .map_err(|error| DecodeError::OtherString(error.to_string()))
+    }
+}
+
+bincode::impl_borrow_decode!(Id);
+
+impl<T: Tagged> Encode for TypedId<T> {
+    fn encode<E: Encoder>(&self, encoder: &mut E) -> Result<(), EncodeError> {
+        Encode::encode(&self.to_id(), encoder)
+    }
+}
+
+impl<T: Tagged, Context> Decode<Context> for TypedId<T> {
+    fn decode<D: Decoder<Context = Context>>(decoder: &mut D) -> Result<Self, DecodeError> {
+        Self::from_id(Id::decode(decoder)?)
+            .map_err(|error| DecodeError::OtherString(error.to_string()))
+    }
+}
+
+impl<'de, T: Tagged, Context> BorrowDecode<'de, Context> for TypedId<T> {
+    fn borrow_decode<D: BorrowDecoder<'de, Context = Context>>(
+        decoder: &mut D,
+    ) -> Result<Self, DecodeError> {
+        Self::decode(decoder)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{id::Id, prefix::Prefix, tagged::Tagged, typed::TypedId};
+    use bincode::config;
+
+    struct User;
+
+    impl Tagged for User {
+        const PREFIX: Prefix = Prefix::from_str_const("user");
+    }
+
+    #[test]
+    fn round_trip() {
+        let id = Id::parse("user_02v58c5a3fy30k560qrtg4").unwrap();
+        let bytes = bincode::encode_to_vec(id, config::standard()).unwrap();
+
+        assert_eq!(id.as_bytes(), bytes.as_slice());
+        assert_eq!(
+            (id, 16),
+            bincode::decode_from_slice::<Id, _>(&bytes, config::standard()).unwrap()
+        );
+        assert_eq!(
+            (id, 16),
+            bincode::borrow_decode_from_slice::<Id, _>(&bytes, config::standard()).unwrap()
+        );
+        assert!(bincode::decode_from_slice::<Id, _>(&[0; 16], config::standard()).is_err());
+    }
+
+    #[test]
+    fn typed() {
+        let id = TypedId::<User>::parse("user_02v58c5a3fy30k560qrtg4").unwrap();
+        let bytes = bincode::encode_to_vec(id, config::standard()).unwrap();
+
+        assert_eq!(id.as_bytes(), bytes.as_slice());
+        assert_eq!(
+            (id, 16),
+            bincode::decode_from_slice::<TypedId<User>, _>(&bytes, config::standard()).unwrap()
+        );
+
+        let other = Id::parse("org_02v58c5a3fy30k560qrtg4").unwrap();
+        assert!(
+            bincode::decode_from_slice::<TypedId<User>, _>(other.as_bytes(), config::standard())
+                .is_err()
+        );
+    }
+}
@@ -0,0 +1,68 @@
+use crate::{
+    id::{Id, IdBytes},
+    tagged::Tagged,
+    typed::TypedId,
+};
+use alloc::string::ToString;
+use borsh::io::{Error, ErrorKind, Read, Result, Write};
+use borsh::{BorshDeserialize, BorshSerialize};
+
+impl BorshSerialize for Id {
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        writer.write_all(self.as_bytes())
+    }
+}
+
+impl BorshDeserialize for Id {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        Self::from_bytes(IdBytes::deserialize_reader(reader)?)
+            .map_err(|error| Error::new(ErrorKind::InvalidData, error.to_string()))
+    }
+}
+
+impl<T: Tagged> BorshSerialize for TypedId<T> {
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        self.to_id().serialize(writer)
+    }
+}
+
+impl<T: Tagged> BorshDeserialize for TypedId<T> {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        Self::from_id(Id::deserialize_reader(reader)?)
+            .map_err(|error| Error::new(ErrorKind::InvalidData, error.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{id::Id, prefix::Prefix, tagged::Tagged, typed::TypedId};
+
+    struct User;
+
+    impl Tagged for User {
+        const PREFIX: Prefix = Prefix::from_str_const("user");
+    }
+
+    #[test]
+    fn round_trip() {
+        let id = Id::parse("user_02v58c5a3fy30k560qrtg4").unwrap();
+        let bytes = borsh::to_vec(&id).unwrap();
+
+        assert_eq!(id.as_bytes(), bytes.as_slice());
+        assert_eq!(id, borsh::from_slice::<Id>(&bytes).unwrap());
+        assert!(borsh::from_slice::<Id>(&[0; 16]).is_err());
+        assert!(borsh::from_slice::<Id>(&bytes[..15]).is_err());
+    }
+
+    #[test]
+    fn typed() {
+        let id = TypedId::<User>::parse("user_02v58c5a3fy30k560qrtg4").unwrap();
+        let bytes = borsh::to_vec(&id).unwrap();
+
+        assert_eq!(id.as_bytes(), bytes.as_slice());
+        assert_eq!(id, borsh::from_slice::<TypedId<User>>(&bytes).unwrap());
+
+        let other = Id::parse("org_02v58c5a3fy30k560qrtg4").unwrap();
+        assert!(borsh::from_slice::<TypedId<User>>(other.as_bytes()).is_err());
+    }
+}
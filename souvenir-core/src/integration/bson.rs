@@ -0,0 +1,103 @@
+use crate::{
+    error::{Error, Result},
+    id::Id,
+    tagged::Tagged,
+    typed::TypedId,
+};
+use bson::{Binary, Bson, spec::BinarySubtype};
+
+impl Id {
+    /// Convert this [`Id`] into a BSON [`Binary`] with the provided subtype.
+    ///
+    /// Binary values are compared by their bytes in MongoDB, so identifiers
+    /// stored this way sort in the same order as [`Id`]s.
+    pub fn to_bson_binary(self, subtype: BinarySubtype) -> Binary {
+        Binary {
+            subtype,
+            bytes: self.to_bytes().to_vec(),
+        }
+    }
+}
+
+impl From<Id> for Bson {
+    /// Convert an [`Id`] into a [`Bson::Binary`] with the generic subtype.
+    fn from(value: Id) -> Self {
+        Bson::Binary(value.to_bson_binary(BinarySubtype::Generic))
+    }
+}
+
+impl TryFrom<Bson> for Id {
+    type Error = Error;
+
+    /// Convert a [`Bson::Binary`] with the generic or UUID subtype into an
+    /// [`Id`]. Identifiers stored as a [`Bson::String`] are also accepted.
+    fn try_from(value: Bson) -> Result<Self> {
+        match value {
+            Bson::Binary(Binary {
+                subtype: BinarySubtype::Generic | BinarySubtype::Uuid,
+                bytes,
+            }) => Self::try_from(bytes.as_slice()),
+            Bson::String(value) => Self::parse(&value),
+            _ => Err(Error::InvalidData),
+        }
+    }
+}
+
+impl<T: Tagged> From<TypedId<T>> for Bson {
+    fn from(value: TypedId<T>) -> Self {
+        value.to_id().into()
+    }
+}
+
+impl<T: Tagged> TryFrom<Bson> for TypedId<T> {
+    type Error = Error;
+
+    fn try_from(value: Bson) -> Result<Self> {
+        Id::try_from(value).and_then(Self::from_id)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{error::Error, id::Id, prefix::Prefix, serde, suffix::Suffix};
+    use bson::{Binary, Bson, Deserializer, Serializer, spec::BinarySubtype};
+    use rand::random;
+
+    const USER: Prefix = Prefix::from_str_const("user");
+
+    #[test]
+    fn convert() {
+        let id = Id::new(USER, Suffix::new(random()));
+        let bson = Bson::from(id);
+
+        assert_eq!(
+            Bson::Binary(Binary {
+                subtype: BinarySubtype::Generic,
+                bytes: id.to_bytes().to_vec()
+            }),
+            bson
+        );
+        assert_eq!(Ok(id), Id::try_from(bson));
+        assert_eq!(Ok(id), Id::try_from(Bson::String(id.to_string())));
+        assert_eq!(Err(Error::InvalidData), Id::try_from(Bson::Int32(1)));
+    }
+
+    #[test]
+    fn with() {
+        let id = Id::new(USER, Suffix::new(random()));
+
+        let bson = serde::bson_binary::serialize(&id, Serializer::new()).unwrap();
+        assert_eq!(Bson::from(id), bson);
+        assert_eq!(
+            id,
+            serde::bson_binary::deserialize::<Id, _>(Deserializer::new(bson)).unwrap()
+        );
+
+        let bson = serde::bson_uuid::serialize(&id, Serializer::new()).unwrap();
+        assert_eq!(Bson::Binary(id.to_bson_binary(BinarySubtype::Uuid)), bson);
+        assert_eq!(
+            id,
+            serde::bson_uuid::deserialize::<Id, _>(Deserializer::new(bson)).unwrap()
+        );
+    }
+}
@@ -0,0 +1,78 @@
+use crate::{
+    encoding::validate_id,
+    id::{Id, IdBytes},
+    tagged::Tagged,
+    typed::TypedId,
+};
+use bytemuck::{CheckedBitPattern, NoUninit};
+
+// SAFETY: `Id` is a transparent wrapper around `[u8; 16]`, which has no
+// padding.
+unsafe impl NoUninit for Id {}
+
+// SAFETY: `Id` has the same layout as `[u8; 16]`, and only bytes which form
+// a valid `Id` are accepted.
+unsafe impl CheckedBitPattern for Id {
+    type Bits = IdBytes;
+
+    fn is_valid_bit_pattern(bits: &Self::Bits) -> bool {
+        validate_id(*bits).is_ok()
+    }
+}
+
+// SAFETY: `TypedId` is a transparent wrapper around `Id`.
+unsafe impl<T: Tagged + 'static> NoUninit for TypedId<T> {}
+
+// SAFETY: `TypedId` has the same layout as `[u8; 16]`, and only bytes which
+// form a valid `Id` with the prefix of `T` are accepted.
+unsafe impl<T: Tagged + 'static> CheckedBitPattern for TypedId<T> {
+    type Bits = IdBytes;
+
+    fn is_valid_bit_pattern(bits: &Self::Bits) -> bool {
+        validate_id(*bits).is_ok_and(|id| id.prefix() == T::PREFIX)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{id::Id, prefix::Prefix, tagged::Tagged, typed::TypedId};
+    use bytemuck::checked::{CheckedCastError, try_cast_slice, try_from_bytes};
+
+    struct User;
+
+    impl Tagged for User {
+        const PREFIX: Prefix = Prefix::from_str_const("user");
+    }
+
+    #[test]
+    fn cast() {
+        let user = Id::parse("user_02v58c5a3fy30k560qrtg4").unwrap();
+        let org = Id::parse("org_02v58c5a3fy30k560qrtg4").unwrap();
+        let ids = [user, org];
+
+        let bytes: &[u8] = bytemuck::cast_slice(&ids);
+        assert_eq!([user.to_bytes(), org.to_bytes()].as_flattened(), bytes);
+        assert_eq!(Ok(&ids[..]), try_cast_slice::<u8, Id>(bytes));
+        assert_eq!(Ok(&user), try_from_bytes::<Id>(&bytes[..16]));
+
+        assert_eq!(
+            Err(CheckedCastError::InvalidBitPattern),
+            try_from_bytes::<Id>(&[0; 16])
+        );
+    }
+
+    #[test]
+    fn typed() {
+        let user = Id::parse("user_02v58c5a3fy30k560qrtg4").unwrap();
+        let org = Id::parse("org_02v58c5a3fy30k560qrtg4").unwrap();
+
+        assert_eq!(
+            Ok(&TypedId::<User>::from_id(user).unwrap()),
+            try_from_bytes::<TypedId<User>>(user.as_bytes())
+        );
+        assert_eq!(
+            Err(CheckedCastError::InvalidBitPattern),
+            try_from_bytes::<TypedId<User>>(org.as_bytes())
+        );
+    }
+}
@@ -1,6 +1,6 @@
 #[cfg(feature = "postgres")]
 mod pg {
-    use crate::id::Id;
+    use crate::{id::Id, tagged::Tagged, typed::TypedId};
     use diesel::pg::{Pg, PgValue};
     use diesel::{deserialize, serialize, sql_types::Uuid};
     use std::io::Write;
@@ -18,11 +18,24 @@ mod pg {
             value.as_bytes().try_into().map_err(Into::into)
         }
     }
+
+    impl<T: Tagged> serialize::ToSql<Uuid, Pg> for TypedId<T> {
+        fn to_sql<'b>(&'b self, out: &mut serialize::Output<'b, '_, Pg>) -> serialize::Result {
+            <Id as serialize::ToSql<Uuid, Pg>>::to_sql(self.as_id(), out)
+        }
+    }
+
+    impl<T: Tagged> deserialize::FromSql<Uuid, Pg> for TypedId<T> {
+        fn from_sql(value: PgValue<'_>) -> deserialize::Result<Self> {
+            Self::from_id(<Id as deserialize::FromSql<Uuid, Pg>>::from_sql(value)?)
+                .map_err(Into::into)
+        }
+    }
 }
 
 #[cfg(feature = "mysql")]
 mod mysql {
-    use crate::id::Id;
+    use crate::{id::Id, tagged::Tagged, typed::TypedId};
     use diesel::mysql::{Mysql, MysqlValue};
     use diesel::{deserialize, serialize, sql_types::Binary};
     use std::io::Write;
@@ -40,13 +53,31 @@ mod mysql {
             value.as_bytes().try_into().map_err(Into::into)
         }
     }
+
+    impl<T: Tagged> serialize::ToSql<Binary, Mysql> for TypedId<T> {
+        fn to_sql<'b>(&'b self, out: &mut serialize::Output<'b, '_, Mysql>) -> serialize::Result {
+            <Id as serialize::ToSql<Binary, Mysql>>::to_sql(self.as_id(), out)
+        }
+    }
+
+    impl<T: Tagged> deserialize::FromSql<Binary, Mysql> for TypedId<T> {
+        fn from_sql(value: MysqlValue<'_>) -> deserialize::Result<Self> {
+            Self::from_id(<Id as deserialize::FromSql<Binary, Mysql>>::from_sql(
+                value,
+            )?)
+            .map_err(Into::into)
+        }
+    }
 }
 
 #[cfg(feature = "sqlite")]
 mod sqlite {
-    use crate::id::Id;
+    use crate::{blob::decode_sqlite, id::Id, tagged::Tagged, typed::TypedId};
     use diesel::sqlite::{Sqlite, SqliteValue};
-    use diesel::{deserialize, serialize, sql_types::Text};
+    use diesel::{
+        deserialize, serialize,
+        sql_types::{Binary, Text},
+    };
 
     impl serialize::ToSql<Text, Sqlite> for Id {
         fn to_sql<'b>(&'b self, out: &mut serialize::Output<'b, '_, Sqlite>) -> serialize::Result {
@@ -56,10 +87,49 @@ mod sqlite {
     }
 
     impl deserialize::FromSql<Text, Sqlite> for Id {
+        fn from_sql(mut value: SqliteValue<'_, '_, '_>) -> deserialize::Result<Self> {
+            decode_sqlite(value.read_blob()).map_err(Into::into)
+        }
+    }
+
+    impl serialize::ToSql<Binary, Sqlite> for Id {
+        fn to_sql<'b>(&'b self, out: &mut serialize::Output<'b, '_, Sqlite>) -> serialize::Result {
+            out.set_value(self.as_bytes().as_slice());
+            Ok(serialize::IsNull::No)
+        }
+    }
+
+    impl deserialize::FromSql<Binary, Sqlite> for Id {
+        fn from_sql(mut value: SqliteValue<'_, '_, '_>) -> deserialize::Result<Self> {
+            decode_sqlite(value.read_blob()).map_err(Into::into)
+        }
+    }
+
+    impl<T: Tagged> serialize::ToSql<Text, Sqlite> for TypedId<T> {
+        fn to_sql<'b>(&'b self, out: &mut serialize::Output<'b, '_, Sqlite>) -> serialize::Result {
+            <Id as serialize::ToSql<Text, Sqlite>>::to_sql(self.as_id(), out)
+        }
+    }
+
+    impl<T: Tagged> deserialize::FromSql<Text, Sqlite> for TypedId<T> {
         fn from_sql(value: SqliteValue<'_, '_, '_>) -> deserialize::Result<Self> {
-            <String as deserialize::FromSql<Text, Sqlite>>::from_sql(value)?
-                .parse()
+            Self::from_id(<Id as deserialize::FromSql<Text, Sqlite>>::from_sql(value)?)
                 .map_err(Into::into)
         }
     }
+
+    impl<T: Tagged> serialize::ToSql<Binary, Sqlite> for TypedId<T> {
+        fn to_sql<'b>(&'b self, out: &mut serialize::Output<'b, '_, Sqlite>) -> serialize::Result {
+            <Id as serialize::ToSql<Binary, Sqlite>>::to_sql(self.as_id(), out)
+        }
+    }
+
+    impl<T: Tagged> deserialize::FromSql<Binary, Sqlite> for TypedId<T> {
+        fn from_sql(value: SqliteValue<'_, '_, '_>) -> deserialize::Result<Self> {
+            Self::from_id(<Id as deserialize::FromSql<Binary, Sqlite>>::from_sql(
+                value,
+            )?)
+            .map_err(Into::into)
+        }
+    }
 }
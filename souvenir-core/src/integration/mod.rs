@@ -1,11 +1,56 @@
+#[cfg(feature = "actix-web")]
+mod actix;
+
+#[cfg(feature = "axum")]
+mod axum;
+
+#[cfg(feature = "bincode")]
+mod bincode;
+
+#[cfg(feature = "borsh")]
+mod borsh;
+
+#[cfg(feature = "bson")]
+mod bson;
+
+#[cfg(feature = "bytemuck")]
+mod bytemuck;
+
 #[cfg(feature = "diesel")]
 mod diesel;
 
+#[cfg(feature = "postgres-types")]
+mod postgres;
+
 #[cfg(feature = "sqlx")]
 mod sqlx;
 
 #[cfg(feature = "rand")]
 mod rand;
 
+#[cfg(feature = "rkyv")]
+mod rkyv;
+
+#[cfg(feature = "rusqlite")]
+mod rusqlite;
+
+#[cfg(any(feature = "schemars", feature = "utoipa"))]
+mod schema;
+
+#[cfg(feature = "schemars")]
+mod schemars;
+
+#[cfg(feature = "sea-orm")]
+mod sea_orm;
+
 #[cfg(feature = "serde")]
 mod serde;
+
+#[cfg(feature = "utoipa")]
+mod utoipa;
+
+#[cfg(feature = "uuid")]
+mod uuid;
+
+#[cfg(feature = "zerocopy")]
+mod zerocopy;
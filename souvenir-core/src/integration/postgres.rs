@@ -0,0 +1,86 @@
+use crate::{id::Id, tagged::Tagged, typed::TypedId};
+use bytes::BytesMut;
+use postgres_types::{FromSql, IsNull, ToSql, Type, accepts, to_sql_checked};
+use std::error::Error;
+
+impl ToSql for Id {
+    fn to_sql(&self, _: &Type, out: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+        out.extend_from_slice(self.as_bytes());
+        Ok(IsNull::No)
+    }
+
+    accepts!(UUID);
+    to_sql_checked!();
+}
+
+impl FromSql<'_> for Id {
+    fn from_sql(_: &Type, raw: &[u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
+        Self::try_from(raw).map_err(Into::into)
+    }
+
+    accepts!(UUID);
+}
+
+impl<T: Tagged> ToSql for TypedId<T> {
+    fn to_sql(
+        &self,
+        ty: &Type,
+        out: &mut BytesMut,
+    ) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+        self.as_id().to_sql(ty, out)
+    }
+
+    accepts!(UUID);
+    to_sql_checked!();
+}
+
+impl<T: Tagged> FromSql<'_> for TypedId<T> {
+    fn from_sql(ty: &Type, raw: &[u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
+        Self::from_id(Id::from_sql(ty, raw)?).map_err(Into::into)
+    }
+
+    accepts!(UUID);
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{id::Id, prefix::Prefix, suffix::Suffix, tagged::Tagged, typed::TypedId};
+    use bytes::BytesMut;
+    use postgres_types::{FromSql, ToSql, Type};
+    use rand::random;
+
+    struct User;
+
+    impl Tagged for User {
+        const PREFIX: Prefix = Prefix::from_str_const("user");
+    }
+
+    #[test]
+    fn round_trip() {
+        let id = Id::new(User::PREFIX, Suffix::new(random()));
+        let mut buf = BytesMut::new();
+
+        id.to_sql_checked(&Type::UUID, &mut buf).unwrap();
+        assert_eq!(id.as_bytes(), &buf[..]);
+        assert_eq!(id, Id::from_sql(&Type::UUID, &buf).unwrap());
+        assert_eq!(
+            id,
+            TypedId::<User>::from_sql(&Type::UUID, &buf)
+                .unwrap()
+                .to_id()
+        );
+        assert!(id.to_sql_checked(&Type::TEXT, &mut buf).is_err());
+    }
+
+    #[test]
+    fn array() {
+        let ids: Vec<Id> = (0..3)
+            .map(|_| Id::new(User::PREFIX, Suffix::new(random())))
+            .collect();
+        let mut buf = BytesMut::new();
+
+        ids.to_sql_checked(&Type::UUID_ARRAY, &mut buf).unwrap();
+        assert_eq!(ids, Vec::<Id>::from_sql(&Type::UUID_ARRAY, &buf).unwrap());
+        assert!(<Vec<TypedId<User>> as FromSql>::accepts(&Type::UUID_ARRAY));
+    }
+}
@@ -1,7 +1,19 @@
-use crate::{id::Id, prefix::Prefix, suffix::Suffix};
-use rand::{Rng, random};
+#[cfg(feature = "std")]
+use crate::clock::{Clock, SystemClock};
+use crate::{
+    id::Id,
+    long::{LongId, LongPrefix},
+    prefix::Prefix,
+    suffix::Suffix,
+    tagged::Tagged,
+    typed::TypedId,
+};
+use rand::Rng;
+#[cfg(feature = "std")]
+use rand::random;
 
 impl Id {
+    #[cfg(feature = "std")]
     /// Generate an [`Id`] with a random value.
     pub fn random(prefix: Prefix) -> Self {
         Self::new(prefix, Suffix::random())
@@ -11,9 +23,49 @@ impl Id {
     pub fn random_with<R: Rng + ?Sized>(prefix: Prefix, rng: &mut R) -> Self {
         Self::new(prefix, Suffix::random_with(rng))
     }
+
+    #[cfg(feature = "std")]
+    /// Generate a time-ordered [`Id`] using the current system time.
+    pub fn timestamped(prefix: Prefix) -> Self {
+        Self::new(prefix, Suffix::timestamped())
+    }
+
+    #[cfg(feature = "std")]
+    /// Generate a time-ordered [`Id`] using the current system time and the
+    /// provided RNG.
+    pub fn timestamped_with<R: Rng + ?Sized>(prefix: Prefix, rng: &mut R) -> Self {
+        Self::new(prefix, Suffix::timestamped_with(rng))
+    }
+}
+
+impl<T: Tagged> TypedId<T> {
+    #[cfg(feature = "std")]
+    /// Generate a [`TypedId`] with a random value.
+    pub fn random() -> Self {
+        Self::new(Suffix::random())
+    }
+
+    /// Generate a random [`TypedId`] with the provided RNG.
+    pub fn random_with<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        Self::new(Suffix::random_with(rng))
+    }
+
+    #[cfg(feature = "std")]
+    /// Generate a time-ordered [`TypedId`] using the current system time.
+    pub fn timestamped() -> Self {
+        Self::new(Suffix::timestamped())
+    }
+
+    #[cfg(feature = "std")]
+    /// Generate a time-ordered [`TypedId`] using the current system time and
+    /// the provided RNG.
+    pub fn timestamped_with<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        Self::new(Suffix::timestamped_with(rng))
+    }
 }
 
 impl Suffix {
+    #[cfg(feature = "std")]
     /// Generate a [`Suffix`] with a random value.
     pub fn random() -> Self {
         Self::new(random())
@@ -23,4 +75,30 @@ impl Suffix {
     pub fn random_with<R: Rng + ?Sized>(rng: &mut R) -> Self {
         Self::new(rng.random())
     }
+
+    #[cfg(feature = "std")]
+    /// Generate a time-ordered [`Suffix`] using the current system time.
+    pub fn timestamped() -> Self {
+        Self::from_timestamp(SystemClock.now(), random())
+    }
+
+    #[cfg(feature = "std")]
+    /// Generate a time-ordered [`Suffix`] using the current system time and
+    /// the provided RNG.
+    pub fn timestamped_with<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        Self::from_timestamp(SystemClock.now(), rng.random())
+    }
+}
+
+impl LongId {
+    #[cfg(feature = "std")]
+    /// Generate a [`LongId`] with a random value.
+    pub fn random(prefix: LongPrefix) -> Self {
+        Self::new(prefix, random())
+    }
+
+    /// Generate a random [`LongId`] with the provided RNG.
+    pub fn random_with<R: Rng + ?Sized>(prefix: LongPrefix, rng: &mut R) -> Self {
+        Self::new(prefix, rng.random())
+    }
 }
@@ -0,0 +1,152 @@
+use crate::{
+    id::{Id, IdBytes},
+    tagged::Tagged,
+    typed::TypedId,
+};
+use rkyv::bytecheck::CheckBytes;
+use rkyv::rancor::{Fallible, Source};
+use rkyv::traits::{CopyOptimization, NoUndef};
+use rkyv::{Archive, Deserialize, Place, Portable, Serialize};
+
+// SAFETY: `Id` is a transparent wrapper around `[u8; 16]`, which has no
+// padding, an alignment of 1 and the same layout on every platform.
+unsafe impl Portable for Id {}
+
+// SAFETY: `[u8; 16]` is always fully initialized.
+unsafe impl NoUndef for Id {}
+
+// SAFETY: `TypedId` is a transparent wrapper around `Id`.
+unsafe impl<T: Tagged> Portable for TypedId<T> {}
+
+// SAFETY: `TypedId` is a transparent wrapper around `Id`.
+unsafe impl<T: Tagged> NoUndef for TypedId<T> {}
+
+impl Archive for Id {
+    // SAFETY: `Id` is its own archived form.
+    const COPY_OPTIMIZATION: CopyOptimization<Self> = unsafe { CopyOptimization::enable() };
+
+    type Archived = Id;
+    type Resolver = ();
+
+    fn resolve(&self, _: Self::Resolver, out: Place<Self::Archived>) {
+        out.write(*self);
+    }
+}
+
+impl<S: Fallible + ?Sized> Serialize<S> for Id {
+    fn serialize(&self, _: &mut S) -> Result<Self::Resolver, S::Error> {
+        Ok(())
+    }
+}
+
+impl<D: Fallible + ?Sized> Deserialize<Id, D> for Id {
+    fn deserialize(&self, _: &mut D) -> Result<Id, D::Error> {
+        Ok(*self)
+    }
+}
+
+// SAFETY: the bytes are only accepted if they form a valid `Id`.
+unsafe impl<C> CheckBytes<C> for Id
+where
+    C: Fallible + ?Sized,
+    C::Error: Source,
+{
+    unsafe fn check_bytes(value: *const Self, _: &mut C) -> Result<(), C::Error> {
+        // SAFETY: the caller guarantees that `value` points to 16 readable
+        // bytes, and any 16 bytes are a valid `[u8; 16]`.
+        let bytes = unsafe { value.cast::<IdBytes>().read() };
+
+        Id::from_bytes(bytes).map(drop).map_err(Source::new)
+    }
+}
+
+impl<T: Tagged> Archive for TypedId<T> {
+    // SAFETY: `TypedId` is its own archived form.
+    const COPY_OPTIMIZATION: CopyOptimization<Self> = unsafe { CopyOptimization::enable() };
+
+    type Archived = TypedId<T>;
+    type Resolver = ();
+
+    fn resolve(&self, _: Self::Resolver, out: Place<Self::Archived>) {
+        out.write(*self);
+    }
+}
+
+impl<T: Tagged, S: Fallible + ?Sized> Serialize<S> for TypedId<T> {
+    fn serialize(&self, _: &mut S) -> Result<Self::Resolver, S::Error> {
+        Ok(())
+    }
+}
+
+impl<T: Tagged, D: Fallible + ?Sized> Deserialize<TypedId<T>, D> for TypedId<T> {
+    fn deserialize(&self, _: &mut D) -> Result<TypedId<T>, D::Error> {
+        Ok(*self)
+    }
+}
+
+// SAFETY: the bytes are only accepted if they form a valid `Id` with the
+// prefix of `T`.
+unsafe impl<T, C> CheckBytes<C> for TypedId<T>
+where
+    T: Tagged,
+    C: Fallible + ?Sized,
+    C::Error: Source,
+{
+    unsafe fn check_bytes(value: *const Self, _: &mut C) -> Result<(), C::Error> {
+        // SAFETY: the caller guarantees that `value` points to 16 readable
+        // bytes, and any 16 bytes are a valid `[u8; 16]`.
+        let bytes = unsafe { value.cast::<IdBytes>().read() };
+
+        Id::from_bytes(bytes)
+            .and_then(TypedId::<T>::from_id)
+            .map(drop)
+            .map_err(Source::new)
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod test {
+    use crate::{error::Error, id::Id, prefix::Prefix, tagged::Tagged, typed::TypedId};
+    use rkyv::rancor;
+
+    struct User;
+
+    impl Tagged for User {
+        const PREFIX: Prefix = Prefix::from_str_const("user");
+    }
+
+    #[test]
+    fn round_trip() {
+        let id = Id::parse("user_02v58c5a3fy30k560qrtg4").unwrap();
+        let bytes = rkyv::to_bytes::<rancor::Error>(&id).unwrap();
+
+        assert_eq!(id.as_bytes(), bytes.as_slice());
+        assert_eq!(&id, rkyv::access::<Id, rancor::Error>(&bytes).unwrap());
+        assert_eq!(id, rkyv::from_bytes::<Id, rancor::Error>(&bytes).unwrap());
+        assert!(rkyv::access::<Id, rancor::Error>(&[0; 16]).is_err());
+    }
+
+    #[test]
+    fn typed() {
+        let id = TypedId::<User>::parse("user_02v58c5a3fy30k560qrtg4").unwrap();
+        let bytes = rkyv::to_bytes::<rancor::Error>(&id).unwrap();
+
+        assert_eq!(id.as_bytes(), bytes.as_slice());
+        assert_eq!(
+            &id,
+            rkyv::access::<TypedId<User>, rancor::Error>(&bytes).unwrap()
+        );
+
+        let other = Id::parse("org_02v58c5a3fy30k560qrtg4").unwrap();
+        let error = rkyv::access::<TypedId<User>, rancor::Error>(other.as_bytes()).unwrap_err();
+        assert!(
+            error.to_string().contains(
+                &Error::PrefixMismatch {
+                    expected: User::PREFIX,
+                    found: other.prefix()
+                }
+                .to_string()
+            )
+        );
+    }
+}
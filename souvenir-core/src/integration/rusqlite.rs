@@ -0,0 +1,113 @@
+use crate::{
+    blob::{BlobId, decode_sqlite},
+    id::Id,
+    identifiable::Identifiable,
+    tagged::Tagged,
+    typed::TypedId,
+};
+use rusqlite::types::{FromSql, FromSqlError, FromSqlResult, ToSql, ToSqlOutput, ValueRef};
+
+impl ToSql for Id {
+    fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+        Ok(ToSqlOutput::from(self.to_string()))
+    }
+}
+
+impl FromSql for Id {
+    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+        match value {
+            ValueRef::Text(bytes) | ValueRef::Blob(bytes) => {
+                decode_sqlite(bytes).map_err(|error| FromSqlError::Other(Box::new(error)))
+            }
+            _ => Err(FromSqlError::InvalidType),
+        }
+    }
+}
+
+impl<T: Tagged> ToSql for TypedId<T> {
+    fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+        Ok(ToSqlOutput::from(self.to_string()))
+    }
+}
+
+impl<T: Tagged> FromSql for TypedId<T> {
+    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+        Self::from_id(Id::column_result(value)?)
+            .map_err(|error| FromSqlError::Other(Box::new(error)))
+    }
+}
+
+impl<T: Identifiable> ToSql for BlobId<T> {
+    fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+        Ok(ToSqlOutput::from(self.0.id().to_bytes().to_vec()))
+    }
+}
+
+impl<T> FromSql for BlobId<T>
+where
+    T: TryFrom<Id>,
+    T::Error: Into<Box<dyn std::error::Error + Send + Sync>>,
+{
+    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+        T::try_from(Id::column_result(value)?)
+            .map(Self)
+            .map_err(|error| FromSqlError::Other(error.into()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        blob::BlobId, id::Id, prefix::Prefix, suffix::Suffix, tagged::Tagged, typed::TypedId,
+    };
+    use rand::random;
+    use rusqlite::Connection;
+
+    struct User;
+
+    impl Tagged for User {
+        const PREFIX: Prefix = Prefix::from_str_const("user");
+    }
+
+    #[test]
+    fn round_trip() {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute("CREATE TABLE ids (id, kind)", ()).unwrap();
+
+        let text = Id::new(User::PREFIX, Suffix::new(random()));
+        let blob = Id::new(User::PREFIX, Suffix::new(random()));
+
+        conn.execute("INSERT INTO ids VALUES (?1, 'text')", [text])
+            .unwrap();
+        conn.execute("INSERT INTO ids VALUES (?1, 'blob')", [BlobId(blob)])
+            .unwrap();
+
+        let kind: String = conn
+            .query_row(
+                "SELECT typeof(id) FROM ids WHERE kind = 'blob'",
+                (),
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!("blob", kind);
+
+        let ids: Vec<TypedId<User>> = conn
+            .prepare("SELECT id FROM ids ORDER BY rowid")
+            .unwrap()
+            .query_map((), |row| row.get(0))
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(
+            vec![text, blob],
+            ids.into_iter().map(Id::from).collect::<Vec<_>>()
+        );
+
+        let BlobId(id): BlobId = conn
+            .query_row("SELECT id FROM ids WHERE kind = 'text'", (), |row| {
+                row.get(0)
+            })
+            .unwrap();
+        assert_eq!(text, id);
+    }
+}
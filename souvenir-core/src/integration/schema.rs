@@ -0,0 +1,127 @@
+use crate::encoding::{ALPHABET, PREFIX};
+use crate::id::Id;
+use crate::suffix::Suffix;
+use crate::tagged::Tagged;
+use alloc::format;
+use alloc::string::{String, ToString};
+
+/// An example [`Id`] used in generated schemas.
+pub const EXAMPLE: &str = "user_02v58c5a3fy30k560qrtg4";
+
+/// An example [`Prefix`](crate::prefix::Prefix) used in generated schemas.
+pub const PREFIX_EXAMPLE: &str = "user";
+
+/// An example [`Suffix`] used in generated schemas.
+pub const SUFFIX_EXAMPLE: &str = "02v58c5a3fy30k560qrtg4";
+
+/// The minimum length of the string representation of an [`Id`].
+pub const MIN_LENGTH: usize = 24;
+
+/// The maximum length of the string representation of an [`Id`].
+pub const MAX_LENGTH: usize = 27;
+
+/// Build a regex character class matching the provided characters, which
+/// must be in ascending order.
+fn char_class(chars: &[u8]) -> String {
+    let mut output = String::from("[");
+    let mut i = 0;
+
+    while i < chars.len() {
+        let start = i;
+
+        while i + 1 < chars.len() && chars[i + 1] == chars[i] + 1 {
+            i += 1;
+        }
+
+        match i - start {
+            0 => output.push(chars[start] as char),
+            1 => {
+                output.push(chars[start] as char);
+                output.push(chars[i] as char);
+            }
+            _ => {
+                output.push(chars[start] as char);
+                output.push('-');
+                output.push(chars[i] as char);
+            }
+        }
+
+        i += 1;
+    }
+
+    output.push(']');
+    output
+}
+
+/// The unanchored pattern of a [`Prefix`].
+fn prefix() -> String {
+    format!("{}{{1,4}}", char_class(&PREFIX[1..27]))
+}
+
+/// The unanchored pattern of a [`Suffix`].
+fn suffix() -> String {
+    format!(
+        "{}{}{{21}}",
+        char_class(&ALPHABET[..8]),
+        char_class(ALPHABET)
+    )
+}
+
+pub fn prefix_pattern() -> String {
+    format!("^{}$", prefix())
+}
+
+pub fn suffix_pattern() -> String {
+    format!("^{}$", suffix())
+}
+
+pub fn id_pattern() -> String {
+    format!("^{}_{}$", prefix(), suffix())
+}
+
+pub fn typed_pattern<T: Tagged>() -> String {
+    format!("^{}_{}$", T::PREFIX, suffix())
+}
+
+pub fn typed_length<T: Tagged>() -> usize {
+    T::PREFIX.to_string().len() + 23
+}
+
+pub fn typed_example<T: Tagged>() -> String {
+    let suffix = Suffix::parse(SUFFIX_EXAMPLE).unwrap_or_default();
+    Id::new(T::PREFIX, suffix).to_string()
+}
+
+/// The name of the schema of a [`TypedId`](crate::typed::TypedId), such as
+/// `UserId` for the `user` prefix.
+pub fn typed_name<T: Tagged>() -> String {
+    let prefix = T::PREFIX.to_string();
+    let mut chars = prefix.chars();
+
+    match chars.next() {
+        Some(first) => format!("{}{}Id", first.to_ascii_uppercase(), chars.as_str()),
+        None => String::from("Id"),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        id::Id,
+        integration::schema::{
+            EXAMPLE, PREFIX_EXAMPLE, SUFFIX_EXAMPLE, id_pattern, prefix_pattern, suffix_pattern,
+        },
+        prefix::Prefix,
+        suffix::Suffix,
+    };
+
+    #[test]
+    fn patterns() {
+        assert_eq!("^[a-z]{1,4}$", prefix_pattern());
+        assert_eq!("^[0-7][0-9a-hjkmnp-tv-z]{21}$", suffix_pattern());
+        assert_eq!("^[a-z]{1,4}_[0-7][0-9a-hjkmnp-tv-z]{21}$", id_pattern());
+        assert!(Id::test(EXAMPLE));
+        assert!(Prefix::parse(PREFIX_EXAMPLE).is_ok());
+        assert!(Suffix::parse(SUFFIX_EXAMPLE).is_ok());
+    }
+}
@@ -0,0 +1,138 @@
+use crate::id::Id;
+use crate::integration::schema::{
+    EXAMPLE, MAX_LENGTH, MIN_LENGTH, PREFIX_EXAMPLE, SUFFIX_EXAMPLE, id_pattern, prefix_pattern,
+    suffix_pattern, typed_example, typed_length, typed_name, typed_pattern,
+};
+use crate::prefix::Prefix;
+use crate::suffix::Suffix;
+use crate::tagged::Tagged;
+use crate::typed::TypedId;
+use alloc::borrow::Cow;
+use alloc::format;
+use schemars::{JsonSchema, Schema, SchemaGenerator, json_schema};
+
+impl JsonSchema for Id {
+    fn schema_name() -> Cow<'static, str> {
+        "Id".into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        "souvenir::Id".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "string",
+            "description": "A prefixed identifier",
+            "pattern": id_pattern(),
+            "minLength": MIN_LENGTH,
+            "maxLength": MAX_LENGTH,
+            "examples": [EXAMPLE],
+        })
+    }
+}
+
+impl<T: Tagged> JsonSchema for TypedId<T> {
+    fn schema_name() -> Cow<'static, str> {
+        typed_name::<T>().into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        format!("souvenir::TypedId<{}>", T::PREFIX).into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "string",
+            "description": format!("A prefixed identifier with the prefix `{}`", T::PREFIX),
+            "pattern": typed_pattern::<T>(),
+            "minLength": typed_length::<T>(),
+            "maxLength": typed_length::<T>(),
+            "examples": [typed_example::<T>()],
+        })
+    }
+}
+
+impl JsonSchema for Prefix {
+    fn schema_name() -> Cow<'static, str> {
+        "Prefix".into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        "souvenir::Prefix".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "string",
+            "description": "The prefix of an identifier",
+            "pattern": prefix_pattern(),
+            "minLength": 1,
+            "maxLength": 4,
+            "examples": [PREFIX_EXAMPLE],
+        })
+    }
+}
+
+impl JsonSchema for Suffix {
+    fn schema_name() -> Cow<'static, str> {
+        "Suffix".into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        "souvenir::Suffix".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "string",
+            "description": "The suffix of an identifier",
+            "pattern": suffix_pattern(),
+            "minLength": 22,
+            "maxLength": 22,
+            "examples": [SUFFIX_EXAMPLE],
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{id::Id, prefix::Prefix, tagged::Tagged, typed::TypedId};
+    use schemars::{JsonSchema, schema_for};
+
+    struct User;
+
+    impl Tagged for User {
+        const PREFIX: Prefix = Prefix::from_str_const("user");
+    }
+
+    #[test]
+    fn id() {
+        let schema = schema_for!(Id);
+
+        assert_eq!(Some("string"), schema.get("type").and_then(|v| v.as_str()));
+        assert_eq!(
+            Some("^[a-z]{1,4}_[0-7][0-9a-hjkmnp-tv-z]{21}$"),
+            schema.get("pattern").and_then(|v| v.as_str())
+        );
+    }
+
+    #[test]
+    fn typed() {
+        let schema = schema_for!(TypedId<User>);
+
+        assert_eq!("UserId", TypedId::<User>::schema_name());
+        assert_eq!(
+            Some("^user_[0-7][0-9a-hjkmnp-tv-z]{21}$"),
+            schema.get("pattern").and_then(|v| v.as_str())
+        );
+        assert_eq!(Some(27), schema.get("minLength").and_then(|v| v.as_u64()));
+        assert_eq!(
+            Some("user_02v58c5a3fy30k560qrtg4"),
+            schema
+                .get("examples")
+                .and_then(|v| v.get(0))
+                .and_then(|v| v.as_str())
+        );
+    }
+}
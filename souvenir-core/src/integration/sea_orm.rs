@@ -0,0 +1,318 @@
+//! Identifiers are stored as `uuid` on Postgres and as `BINARY(16)` on
+//! MySQL, matching the `sqlx` and `diesel` integrations. On SQLite, the
+//! [`TextId`] wrapper stores identifiers as `TEXT` instead, which is how the
+//! other SQLite integrations store them.
+
+use crate::{id::Id, identifiable::Identifiable, tagged::Tagged, text::TextId, typed::TypedId};
+use core::fmt::Display;
+use sea_orm::sea_query::{ArrayType, ColumnType, Nullable, Value, ValueType, ValueTypeErr};
+use sea_orm::{ColIdx, DbErr, QueryResult, TryFromU64, TryGetError, TryGetable};
+use uuid::Uuid;
+
+fn type_error(error: impl Display) -> TryGetError {
+    TryGetError::DbErr(DbErr::Type(error.to_string()))
+}
+
+impl From<Id> for Value {
+    fn from(value: Id) -> Self {
+        Value::Uuid(Some(Box::new(Uuid::from_bytes(value.to_bytes()))))
+    }
+}
+
+impl TryGetable for Id {
+    fn try_get_by<I: ColIdx>(res: &QueryResult, index: I) -> Result<Self, TryGetError> {
+        match Uuid::try_get_by(res, index) {
+            Ok(uuid) => Id::from_bytes(uuid.into_bytes()).map_err(type_error),
+            // Identifiers stored as text in SQLite are not valid UUIDs
+            Err(TryGetError::DbErr(_)) => {
+                String::try_get_by(res, index)?.parse().map_err(type_error)
+            }
+            Err(error) => Err(error),
+        }
+    }
+}
+
+impl ValueType for Id {
+    fn try_from(v: Value) -> Result<Self, ValueTypeErr> {
+        match v {
+            Value::Uuid(Some(uuid)) => Id::from_bytes(uuid.into_bytes()).map_err(|_| ValueTypeErr),
+            Value::String(Some(string)) => string.parse().map_err(|_| ValueTypeErr),
+            _ => Err(ValueTypeErr),
+        }
+    }
+
+    fn type_name() -> String {
+        "Id".to_owned()
+    }
+
+    fn array_type() -> ArrayType {
+        ArrayType::Uuid
+    }
+
+    /// SeaORM creates `uuid` columns on Postgres and `binary(16)` columns on
+    /// MySQL for [`ColumnType::Uuid`].
+    fn column_type() -> ColumnType {
+        ColumnType::Uuid
+    }
+}
+
+impl Nullable for Id {
+    fn null() -> Value {
+        Value::Uuid(None)
+    }
+}
+
+impl TryFromU64 for Id {
+    fn try_from_u64(_: u64) -> Result<Self, DbErr> {
+        Err(DbErr::ConvertFromU64("Id"))
+    }
+}
+
+impl<T: Tagged> From<TypedId<T>> for Value {
+    fn from(value: TypedId<T>) -> Self {
+        value.to_id().into()
+    }
+}
+
+impl<T: Tagged> TryGetable for TypedId<T> {
+    fn try_get_by<I: ColIdx>(res: &QueryResult, index: I) -> Result<Self, TryGetError> {
+        Self::from_id(Id::try_get_by(res, index)?).map_err(type_error)
+    }
+}
+
+impl<T: Tagged> ValueType for TypedId<T> {
+    fn try_from(v: Value) -> Result<Self, ValueTypeErr> {
+        Self::from_id(<Id as ValueType>::try_from(v)?).map_err(|_| ValueTypeErr)
+    }
+
+    fn type_name() -> String {
+        "TypedId".to_owned()
+    }
+
+    fn array_type() -> ArrayType {
+        Id::array_type()
+    }
+
+    fn column_type() -> ColumnType {
+        Id::column_type()
+    }
+}
+
+impl<T: Tagged> Nullable for TypedId<T> {
+    fn null() -> Value {
+        Id::null()
+    }
+}
+
+impl<T: Tagged> TryFromU64 for TypedId<T> {
+    fn try_from_u64(_: u64) -> Result<Self, DbErr> {
+        Err(DbErr::ConvertFromU64("TypedId"))
+    }
+}
+
+impl<T: Identifiable> From<TextId<T>> for Value {
+    fn from(value: TextId<T>) -> Self {
+        Value::String(Some(Box::new(value.0.id().to_string())))
+    }
+}
+
+impl<T> TryGetable for TextId<T>
+where
+    T: TryFrom<Id>,
+    T::Error: Display,
+{
+    fn try_get_by<I: ColIdx>(res: &QueryResult, index: I) -> Result<Self, TryGetError> {
+        T::try_from(Id::try_get_by(res, index)?)
+            .map(Self)
+            .map_err(type_error)
+    }
+}
+
+impl<T> ValueType for TextId<T>
+where
+    T: TryFrom<Id>,
+{
+    fn try_from(v: Value) -> Result<Self, ValueTypeErr> {
+        T::try_from(<Id as ValueType>::try_from(v)?)
+            .map(Self)
+            .map_err(|_| ValueTypeErr)
+    }
+
+    fn type_name() -> String {
+        "TextId".to_owned()
+    }
+
+    fn array_type() -> ArrayType {
+        ArrayType::String
+    }
+
+    fn column_type() -> ColumnType {
+        ColumnType::Text
+    }
+}
+
+impl<T> Nullable for TextId<T> {
+    fn null() -> Value {
+        Value::String(None)
+    }
+}
+
+impl<T> TryFromU64 for TextId<T> {
+    fn try_from_u64(_: u64) -> Result<Self, DbErr> {
+        Err(DbErr::ConvertFromU64("TextId"))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{id::Id, prefix::Prefix, tagged::Tagged, text::TextId, typed::TypedId};
+    use sea_orm::entity::prelude::*;
+    use sea_orm::sea_query::{Value, ValueType};
+    use sea_orm::{DbBackend, QueryTrait, TryFromU64};
+
+    pub struct User;
+
+    impl Tagged for User {
+        const PREFIX: Prefix = Prefix::from_str_const("user");
+    }
+
+    #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+    #[sea_orm(table_name = "users")]
+    pub struct Model {
+        #[sea_orm(primary_key, auto_increment = false)]
+        pub id: TypedId<User>,
+        pub parent: Option<Id>,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {}
+
+    impl ActiveModelBehavior for ActiveModel {}
+
+    #[test]
+    fn value() {
+        let id = Id::parse("user_02v58c5a3fy30k560qrtg4").unwrap();
+        let value = Value::from(id);
+
+        assert_eq!(
+            Ok(id),
+            <Id as ValueType>::try_from(value.clone()).map_err(|_| ())
+        );
+        assert_eq!(
+            Ok(id),
+            <Id as ValueType>::try_from(Value::from(id.to_string())).map_err(|_| ())
+        );
+        assert!(<Id as ValueType>::try_from(Value::Uuid(None)).is_err());
+        assert!(<TypedId<User> as ValueType>::try_from(value).is_ok());
+        assert!(Id::try_from_u64(1).is_err());
+    }
+
+    #[test]
+    fn primary_key() {
+        let id = TypedId::<User>::parse("user_02v58c5a3fy30k560qrtg4").unwrap();
+        let query = Entity::find_by_id(id)
+            .build(DbBackend::Postgres)
+            .to_string();
+
+        assert!(query.contains("'accb202d-950c-2a86-ff0c-1329817c6a04'"));
+    }
+
+    #[test]
+    fn text_value() {
+        let id = TypedId::<User>::parse("user_02v58c5a3fy30k560qrtg4").unwrap();
+        let value = Value::from(TextId(id));
+
+        assert_eq!(Value::from(id.to_string()), value);
+        assert_eq!(
+            Ok(TextId(id)),
+            <TextId<TypedId<User>> as ValueType>::try_from(value).map_err(|_| ())
+        );
+        assert_eq!(
+            Ok(TextId(id)),
+            <TextId<TypedId<User>> as ValueType>::try_from(Value::from(id.to_id())).map_err(|_| ())
+        );
+        assert!(
+            <TextId<TypedId<User>> as ValueType>::try_from(Value::from(
+                "org_02v58c5a3fy30k560qrtg4"
+            ))
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn text_primary_key() {
+        let id = TypedId::<User>::parse("user_02v58c5a3fy30k560qrtg4").unwrap();
+        let query = text::Entity::find_by_id(TextId(id))
+            .build(DbBackend::Sqlite)
+            .to_string();
+
+        assert!(query.contains("'user_02v58c5a3fy30k560qrtg4'"));
+    }
+
+    mod text {
+        use super::User;
+        use crate::{id::Id, text::TextId, typed::TypedId};
+        use sea_orm::entity::prelude::*;
+
+        #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+        #[sea_orm(table_name = "users")]
+        pub struct Model {
+            #[sea_orm(primary_key, auto_increment = false)]
+            pub id: TextId<TypedId<User>>,
+            pub parent: Option<TextId<Id>>,
+        }
+
+        #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+        pub enum Relation {}
+
+        impl ActiveModelBehavior for ActiveModel {}
+    }
+
+    /// Rows written by the `sqlx` integration can be found and read back.
+    #[tokio::test]
+    #[cfg(all(feature = "sqlite", feature = "sqlx"))]
+    async fn sqlite() {
+        use sea_orm::{ActiveModelTrait, ConnectionTrait, Database, Schema, Set};
+
+        let db = Database::connect("sqlite::memory:").await.unwrap();
+        let schema = Schema::new(DbBackend::Sqlite);
+        db.execute(
+            db.get_database_backend()
+                .build(&schema.create_table_from_entity(text::Entity)),
+        )
+        .await
+        .unwrap();
+
+        let id = TypedId::<User>::parse("user_02v58c5a3fy30k560qrtg4").unwrap();
+        let parent = Id::parse("org_02v58c5a3fy30k560qrtg4").unwrap();
+
+        sqlx::query("INSERT INTO users (id, parent) VALUES ($1, $2)")
+            .bind(id)
+            .bind(parent)
+            .execute(db.get_sqlite_connection_pool())
+            .await
+            .unwrap();
+
+        let model = text::Entity::find_by_id(TextId(id))
+            .one(&db)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(Some(TextId(parent)), model.parent);
+
+        let other = TypedId::<User>::parse("user_02v58c5a3fy30k560qrtg5").unwrap();
+        text::ActiveModel {
+            id: Set(TextId(other)),
+            parent: Set(None),
+        }
+        .insert(&db)
+        .await
+        .unwrap();
+
+        let (stored,): (String,) = sqlx::query_as("SELECT id FROM users WHERE parent IS NULL")
+            .fetch_one(db.get_sqlite_connection_pool())
+            .await
+            .unwrap();
+        assert_eq!(other.to_string(), stored);
+    }
+}
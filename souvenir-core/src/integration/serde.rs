@@ -1,7 +1,13 @@
 use crate::id::Id;
+use crate::long::{LongId, LongPrefix};
 use crate::prefix::Prefix;
+use crate::registry::{KnownId, PrefixRegistry, Registry};
 use crate::suffix::Suffix;
-use serde::de::Error;
+use crate::tagged::Tagged;
+use crate::typed::TypedId;
+use alloc::string::ToString;
+use core::fmt::Formatter;
+use serde::de::{DeserializeSeed, Error, Expected, IgnoredAny, SeqAccess, Unexpected, Visitor};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 impl Serialize for Id {
@@ -10,7 +16,7 @@ impl Serialize for Id {
         S: Serializer,
     {
         if serializer.is_human_readable() {
-            self.to_string().serialize(serializer)
+            serializer.serialize_str(&self.encode())
         } else {
             self.to_bytes().serialize(serializer)
         }
@@ -23,17 +29,46 @@ impl<'de> Deserialize<'de> for Id {
         D: Deserializer<'de>,
     {
         if deserializer.is_human_readable() {
-            <String as Deserialize<'de>>::deserialize(deserializer)
-                .map(|str| Self::parse(&str))?
-                .map_err(Error::custom)
+            deserializer.deserialize_str(IdVisitor)
         } else {
-            <[u8; 16] as Deserialize<'de>>::deserialize(deserializer)
-                .map(Self::from_bytes)?
-                .map_err(Error::custom)
+            deserializer.deserialize_tuple(16, IdVisitor)
         }
     }
 }
 
+/// Visitor for an [`Id`] in its string form, as 16 bytes or as a 128-bit
+/// integer. Strings are parsed in place, without allocating.
+struct IdVisitor;
+
+impl<'de> Visitor<'de> for IdVisitor {
+    type Value = Id;
+
+    fn expecting(&self, f: &mut Formatter) -> core::fmt::Result {
+        f.write_str("an identifier such as `user_02v58c5a3fy30k560qrtg4`")
+    }
+
+    fn visit_str<E: Error>(self, v: &str) -> Result<Self::Value, E> {
+        Id::parse(v).map_err(|error| invalid(Unexpected::Str(v), &self, error))
+    }
+
+    fn visit_borrowed_str<E: Error>(self, v: &'de str) -> Result<Self::Value, E> {
+        self.visit_str(v)
+    }
+
+    fn visit_bytes<E: Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+        Id::try_from(v).map_err(|error| invalid(Unexpected::Bytes(v), &self, error))
+    }
+
+    fn visit_u128<E: Error>(self, v: u128) -> Result<Self::Value, E> {
+        Id::try_from(v).map_err(|error| invalid(Unexpected::Other("128-bit integer"), &self, error))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, seq: A) -> Result<Self::Value, A::Error> {
+        let bytes = byte_seq(seq, &self)?;
+        Id::from_bytes(bytes).map_err(|error| invalid(Unexpected::Bytes(&bytes), &self, error))
+    }
+}
+
 impl Serialize for Prefix {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -53,17 +88,47 @@ impl<'de> Deserialize<'de> for Prefix {
         D: Deserializer<'de>,
     {
         if deserializer.is_human_readable() {
-            <String as Deserialize<'de>>::deserialize(deserializer)
-                .map(|str| Self::parse(&str))?
-                .map_err(Error::custom)
+            deserializer.deserialize_str(PrefixVisitor)
         } else {
-            <u32 as Deserialize<'de>>::deserialize(deserializer)
-                .map(Self::new)?
-                .map_err(Error::custom)
+            deserializer.deserialize_u32(PrefixVisitor)
         }
     }
 }
 
+/// Visitor for a [`Prefix`] in its string form or as a 32-bit integer.
+struct PrefixVisitor;
+
+impl<'de> Visitor<'de> for PrefixVisitor {
+    type Value = Prefix;
+
+    fn expecting(&self, f: &mut Formatter) -> core::fmt::Result {
+        f.write_str("a prefix of 1 to 4 lowercase letters such as `user`")
+    }
+
+    fn visit_str<E: Error>(self, v: &str) -> Result<Self::Value, E> {
+        Prefix::parse(v).map_err(|error| invalid(Unexpected::Str(v), &self, error))
+    }
+
+    fn visit_borrowed_str<E: Error>(self, v: &'de str) -> Result<Self::Value, E> {
+        self.visit_str(v)
+    }
+
+    fn visit_bytes<E: Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+        match core::str::from_utf8(v) {
+            Ok(v) => self.visit_str(v),
+            Err(_) => Err(E::invalid_value(Unexpected::Bytes(v), &self)),
+        }
+    }
+
+    fn visit_u64<E: Error>(self, v: u64) -> Result<Self::Value, E> {
+        u32::try_from(v)
+            .map_err(|_| E::invalid_value(Unexpected::Unsigned(v), &self))
+            .and_then(|value| {
+                Prefix::new(value).map_err(|error| invalid(Unexpected::Unsigned(v), &self, error))
+            })
+    }
+}
+
 impl Serialize for Suffix {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -83,11 +148,351 @@ impl<'de> Deserialize<'de> for Suffix {
         D: Deserializer<'de>,
     {
         if deserializer.is_human_readable() {
-            <String as Deserialize<'de>>::deserialize(deserializer)
-                .map(|str| Self::parse(&str))?
-                .map_err(Error::custom)
+            deserializer.deserialize_str(SuffixVisitor)
+        } else {
+            deserializer.deserialize_u128(SuffixVisitor)
+        }
+    }
+}
+
+/// Visitor for a [`Suffix`] in its string form or as a 128-bit integer.
+struct SuffixVisitor;
+
+impl<'de> Visitor<'de> for SuffixVisitor {
+    type Value = Suffix;
+
+    fn expecting(&self, f: &mut Formatter) -> core::fmt::Result {
+        f.write_str("a suffix of 22 characters such as `02v58c5a3fy30k560qrtg4`")
+    }
+
+    fn visit_str<E: Error>(self, v: &str) -> Result<Self::Value, E> {
+        Suffix::parse(v).map_err(|error| invalid(Unexpected::Str(v), &self, error))
+    }
+
+    fn visit_borrowed_str<E: Error>(self, v: &'de str) -> Result<Self::Value, E> {
+        self.visit_str(v)
+    }
+
+    fn visit_bytes<E: Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+        match core::str::from_utf8(v) {
+            Ok(v) => self.visit_str(v),
+            Err(_) => Err(E::invalid_value(Unexpected::Bytes(v), &self)),
+        }
+    }
+
+    fn visit_u64<E: Error>(self, v: u64) -> Result<Self::Value, E> {
+        Ok(Suffix::new(v.into()))
+    }
+
+    fn visit_u128<E: Error>(self, v: u128) -> Result<Self::Value, E> {
+        Ok(Suffix::new(v))
+    }
+}
+
+/// Read exactly 16 bytes from a sequence, as serialized by binary formats.
+fn byte_seq<'de, A: SeqAccess<'de>>(
+    mut seq: A,
+    expected: &dyn Expected,
+) -> Result<[u8; 16], A::Error> {
+    let mut bytes = [0; 16];
+
+    for (i, byte) in bytes.iter_mut().enumerate() {
+        *byte = seq
+            .next_element()?
+            .ok_or_else(|| A::Error::invalid_length(i, expected))?;
+    }
+
+    if seq.next_element::<IgnoredAny>()?.is_some() {
+        return Err(A::Error::invalid_length(17, expected));
+    }
+
+    Ok(bytes)
+}
+
+/// Convert an identifier error into a deserialization error which shows the
+/// expected format.
+fn invalid<E: Error>(
+    unexpected: Unexpected,
+    expected: &dyn Expected,
+    error: crate::error::Error,
+) -> E {
+    E::invalid_value(unexpected, &Invalid(expected, error))
+}
+
+/// The format a visitor expects, followed by the reason a value was
+/// rejected.
+struct Invalid<'a>(&'a dyn Expected, crate::error::Error);
+
+impl Expected for Invalid<'_> {
+    fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+        self.0.fmt(f)?;
+        write!(f, " ({})", self.1)
+    }
+}
+
+impl<T: Tagged> Serialize for TypedId<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.to_id().serialize(serializer)
+    }
+}
+
+impl<'de, T: Tagged> Deserialize<'de> for TypedId<T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        <Id as Deserialize<'de>>::deserialize(deserializer)
+            .map(Self::from_id)?
+            .map_err(Error::custom)
+    }
+}
+
+/// Deserialize an [`Id`], rejecting prefixes which are not in the registry.
+///
+/// ```
+/// # use souvenir_core::{prefix::Prefix, registry::{PrefixInfo, PrefixRegistry}};
+/// # use serde::de::{DeserializeSeed, IntoDeserializer, value::{Error, StrDeserializer}};
+/// let registry = PrefixRegistry::new()
+///     .with(PrefixInfo::new(Prefix::from_str_const("user"), "User", "accounts"));
+///
+/// let known: StrDeserializer<Error> = "user_02v58c5a3fy30k560qrtg4".into_deserializer();
+/// assert!(registry.deserialize(known).is_ok());
+///
+/// let unknown: StrDeserializer<Error> = "team_02v58c5a3fy30k560qrtg4".into_deserializer();
+/// assert!(registry.deserialize(unknown).is_err());
+/// ```
+impl<'de> DeserializeSeed<'de> for &PrefixRegistry {
+    type Value = Id;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let id = <Id as Deserialize<'de>>::deserialize(deserializer)?;
+        self.validate(id).map_err(Error::custom)?;
+
+        Ok(id)
+    }
+}
+
+impl<R: Registry> Serialize for KnownId<R> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.to_id().serialize(serializer)
+    }
+}
+
+impl<'de, R: Registry> Deserialize<'de> for KnownId<R> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        <Id as Deserialize<'de>>::deserialize(deserializer)
+            .map(Self::from_id)?
+            .map_err(Error::custom)
+    }
+}
+
+impl Serialize for LongId {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if serializer.is_human_readable() {
+            self.to_string().serialize(serializer)
+        } else {
+            self.to_bytes().serialize(serializer)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for LongId {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(LongIdVisitor)
+        } else {
+            deserializer.deserialize_tuple(16, LongIdVisitor)
+        }
+    }
+}
+
+/// Visitor for a [`LongId`] in its string form, as 16 bytes or as a 128-bit
+/// integer.
+struct LongIdVisitor;
+
+impl<'de> Visitor<'de> for LongIdVisitor {
+    type Value = LongId;
+
+    fn expecting(&self, f: &mut Formatter) -> core::fmt::Result {
+        f.write_str("an identifier such as `invoice2_0h8cm5a6rx0tc`")
+    }
+
+    fn visit_str<E: Error>(self, v: &str) -> Result<Self::Value, E> {
+        LongId::parse(v).map_err(|error| invalid(Unexpected::Str(v), &self, error))
+    }
+
+    fn visit_borrowed_str<E: Error>(self, v: &'de str) -> Result<Self::Value, E> {
+        self.visit_str(v)
+    }
+
+    fn visit_bytes<E: Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+        LongId::try_from(v).map_err(|error| invalid(Unexpected::Bytes(v), &self, error))
+    }
+
+    fn visit_u128<E: Error>(self, v: u128) -> Result<Self::Value, E> {
+        LongId::try_from(v)
+            .map_err(|error| invalid(Unexpected::Other("128-bit integer"), &self, error))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, seq: A) -> Result<Self::Value, A::Error> {
+        let bytes = byte_seq(seq, &self)?;
+        LongId::from_bytes(bytes).map_err(|error| invalid(Unexpected::Bytes(&bytes), &self, error))
+    }
+}
+
+impl Serialize for LongPrefix {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if serializer.is_human_readable() {
+            self.to_string().serialize(serializer)
+        } else {
+            self.to_u64().serialize(serializer)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for LongPrefix {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(LongPrefixVisitor)
         } else {
-            <u128 as Deserialize<'de>>::deserialize(deserializer).map(Self::new)
+            deserializer.deserialize_u64(LongPrefixVisitor)
+        }
+    }
+}
+
+/// Visitor for a [`LongPrefix`] in its string form or as a 64-bit integer.
+struct LongPrefixVisitor;
+
+impl<'de> Visitor<'de> for LongPrefixVisitor {
+    type Value = LongPrefix;
+
+    fn expecting(&self, f: &mut Formatter) -> core::fmt::Result {
+        f.write_str("a prefix of 1 to 12 lowercase letters and digits such as `invoice2`")
+    }
+
+    fn visit_str<E: Error>(self, v: &str) -> Result<Self::Value, E> {
+        LongPrefix::parse(v).map_err(|error| invalid(Unexpected::Str(v), &self, error))
+    }
+
+    fn visit_borrowed_str<E: Error>(self, v: &'de str) -> Result<Self::Value, E> {
+        self.visit_str(v)
+    }
+
+    fn visit_bytes<E: Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+        match core::str::from_utf8(v) {
+            Ok(v) => self.visit_str(v),
+            Err(_) => Err(E::invalid_value(Unexpected::Bytes(v), &self)),
         }
     }
+
+    fn visit_u64<E: Error>(self, v: u64) -> Result<Self::Value, E> {
+        LongPrefix::new(v).map_err(|error| invalid(Unexpected::Unsigned(v), &self, error))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::long::{LongId, LongPrefix};
+    use crate::{id::Id, prefix::Prefix, suffix::Suffix};
+    use serde::Deserialize;
+    use serde::de::value::{BorrowedStrDeserializer, Error, U128Deserializer};
+
+    const ID: &str = "user_02v58c5a3fy30k560qrtg4";
+    const LONG: &str = "invoice2_0h8cm5a6rx0tc";
+
+    #[test]
+    fn borrowed() {
+        let ids: Vec<Id> = serde_json::from_str(&format!(r#"["{ID}", "{ID}"]"#)).unwrap();
+        assert_eq!(vec![Id::parse(ID).unwrap(); 2], ids);
+
+        let id = Id::deserialize(BorrowedStrDeserializer::<Error>::new(ID)).unwrap();
+        assert_eq!(Id::parse(ID).unwrap(), id);
+
+        let prefix = Prefix::deserialize(BorrowedStrDeserializer::<Error>::new("user")).unwrap();
+        assert_eq!(Prefix::parse("user").unwrap(), prefix);
+
+        let suffix = Suffix::deserialize(U128Deserializer::<Error>::new(42)).unwrap();
+        assert_eq!(Suffix::new(42), suffix);
+
+        let long = LongId::deserialize(BorrowedStrDeserializer::<Error>::new(LONG)).unwrap();
+        assert_eq!(LongId::parse(LONG).unwrap(), long);
+
+        let prefix =
+            LongPrefix::deserialize(BorrowedStrDeserializer::<Error>::new("invoice2")).unwrap();
+        assert_eq!(LongPrefix::parse("invoice2").unwrap(), prefix);
+    }
+
+    #[test]
+    fn invalid() {
+        let error = serde_json::from_str::<Id>(r#""User_02v58c5a3fy30k560qrtg4""#)
+            .unwrap_err()
+            .to_string();
+
+        assert_eq!(
+            "invalid value: string \"User_02v58c5a3fy30k560qrtg4\", expected an identifier such as `user_02v58c5a3fy30k560qrtg4` (invalid prefix `User` at byte 0: uppercase letters are not allowed) at line 1 column 29",
+            error
+        );
+
+        let error = serde_json::from_str::<Prefix>(r#""users""#).unwrap_err();
+        assert!(
+            error
+                .to_string()
+                .contains("expected a prefix of 1 to 4 lowercase letters")
+        );
+
+        let error = serde_json::from_str::<LongId>(r#""Invoice2_0h8cm5a6rx0tc""#).unwrap_err();
+        assert!(
+            error
+                .to_string()
+                .contains("expected an identifier such as `invoice2_0h8cm5a6rx0tc` (")
+        );
+
+        let error = serde_json::from_str::<LongPrefix>(r#""2invoice""#).unwrap_err();
+        assert!(
+            error
+                .to_string()
+                .contains("expected a prefix of 1 to 12 lowercase letters and digits")
+        );
+    }
+
+    #[test]
+    fn postcard() {
+        let id = Id::parse(ID).unwrap();
+        let bytes = postcard::to_allocvec(&id).unwrap();
+
+        assert_eq!(id.as_bytes(), bytes.as_slice());
+        assert_eq!(id, postcard::from_bytes::<Id>(&bytes).unwrap());
+
+        let long = LongId::parse(LONG).unwrap();
+        let bytes = postcard::to_allocvec(&long).unwrap();
+        assert_eq!(long, postcard::from_bytes::<LongId>(&bytes).unwrap());
+
+        let prefix = long.prefix();
+        let bytes = postcard::to_allocvec(&prefix).unwrap();
+        assert_eq!(prefix, postcard::from_bytes::<LongPrefix>(&bytes).unwrap());
+    }
 }
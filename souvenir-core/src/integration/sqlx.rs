@@ -1,6 +1,6 @@
 #[cfg(feature = "postgres")]
 mod pg {
-    use crate::id::Id;
+    use crate::{id::Id, tagged::Tagged, typed::TypedId};
     use sqlx::postgres::{
         PgArgumentBuffer, PgHasArrayType, PgTypeInfo, PgValueFormat, PgValueRef, Postgres,
         types::Oid,
@@ -35,11 +35,35 @@ mod pg {
             .map_err(Into::into)
         }
     }
+
+    impl<T: Tagged> Type<Postgres> for TypedId<T> {
+        fn type_info() -> PgTypeInfo {
+            <Id as Type<Postgres>>::type_info()
+        }
+    }
+
+    impl<T: Tagged> PgHasArrayType for TypedId<T> {
+        fn array_type_info() -> PgTypeInfo {
+            <Id as PgHasArrayType>::array_type_info()
+        }
+    }
+
+    impl<T: Tagged> Encode<'_, Postgres> for TypedId<T> {
+        fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+            <Id as Encode<Postgres>>::encode(self.to_id(), buf)
+        }
+    }
+
+    impl<T: Tagged> Decode<'_, Postgres> for TypedId<T> {
+        fn decode(value: PgValueRef) -> Result<Self, BoxDynError> {
+            Self::from_id(<Id as Decode<Postgres>>::decode(value)?).map_err(Into::into)
+        }
+    }
 }
 
 #[cfg(feature = "mysql")]
 mod mysql {
-    use crate::id::Id;
+    use crate::{id::Id, tagged::Tagged, typed::TypedId};
     use sqlx::mysql::{MySql, MySqlTypeInfo, MySqlValueRef};
     use sqlx::{Decode, Encode, Type, encode::IsNull, error::BoxDynError};
 
@@ -65,11 +89,39 @@ mod mysql {
             Self::try_from(bytes).map_err(Into::into)
         }
     }
+
+    impl<T: Tagged> Type<MySql> for TypedId<T> {
+        fn type_info() -> MySqlTypeInfo {
+            <Id as Type<MySql>>::type_info()
+        }
+
+        fn compatible(ty: &MySqlTypeInfo) -> bool {
+            <Id as Type<MySql>>::compatible(ty)
+        }
+    }
+
+    impl<T: Tagged> Encode<'_, MySql> for TypedId<T> {
+        fn encode_by_ref(&self, buf: &mut Vec<u8>) -> Result<IsNull, BoxDynError> {
+            <Id as Encode<MySql>>::encode(self.to_id(), buf)
+        }
+    }
+
+    impl<T: Tagged> Decode<'_, MySql> for TypedId<T> {
+        fn decode(value: MySqlValueRef) -> Result<Self, BoxDynError> {
+            Self::from_id(<Id as Decode<MySql>>::decode(value)?).map_err(Into::into)
+        }
+    }
 }
 
 #[cfg(feature = "sqlite")]
 mod sqlite {
-    use crate::id::Id;
+    use crate::{
+        blob::{BlobId, decode_sqlite},
+        id::Id,
+        identifiable::Identifiable,
+        tagged::Tagged,
+        typed::TypedId,
+    };
     use sqlx::sqlite::{Sqlite, SqliteArgumentValue, SqliteTypeInfo, SqliteValueRef};
     use sqlx::{Decode, Encode, Type, encode::IsNull, error::BoxDynError};
     use std::borrow::Cow;
@@ -81,6 +133,7 @@ mod sqlite {
 
         fn compatible(ty: &SqliteTypeInfo) -> bool {
             <&str as sqlx::Type<Sqlite>>::compatible(ty)
+                || <&[u8] as sqlx::Type<Sqlite>>::compatible(ty)
         }
     }
 
@@ -96,8 +149,64 @@ mod sqlite {
 
     impl Decode<'_, Sqlite> for Id {
         fn decode(value: SqliteValueRef<'_>) -> Result<Self, BoxDynError> {
-            <&str as Decode<Sqlite>>::decode(value)?
-                .parse()
+            decode_sqlite(<&[u8] as Decode<Sqlite>>::decode(value)?).map_err(Into::into)
+        }
+    }
+
+    impl<T: Tagged> Type<Sqlite> for TypedId<T> {
+        fn type_info() -> SqliteTypeInfo {
+            <Id as Type<Sqlite>>::type_info()
+        }
+
+        fn compatible(ty: &SqliteTypeInfo) -> bool {
+            <Id as Type<Sqlite>>::compatible(ty)
+        }
+    }
+
+    impl<'q, T: Tagged> Encode<'q, Sqlite> for TypedId<T> {
+        fn encode_by_ref(
+            &self,
+            args: &mut Vec<SqliteArgumentValue<'q>>,
+        ) -> Result<IsNull, BoxDynError> {
+            <Id as Encode<Sqlite>>::encode(self.to_id(), args)
+        }
+    }
+
+    impl<T: Tagged> Decode<'_, Sqlite> for TypedId<T> {
+        fn decode(value: SqliteValueRef<'_>) -> Result<Self, BoxDynError> {
+            Self::from_id(<Id as Decode<Sqlite>>::decode(value)?).map_err(Into::into)
+        }
+    }
+
+    impl<T> Type<Sqlite> for BlobId<T> {
+        fn type_info() -> SqliteTypeInfo {
+            <&[u8] as sqlx::Type<Sqlite>>::type_info()
+        }
+
+        fn compatible(ty: &SqliteTypeInfo) -> bool {
+            <Id as Type<Sqlite>>::compatible(ty)
+        }
+    }
+
+    impl<'q, T: Identifiable> Encode<'q, Sqlite> for BlobId<T> {
+        fn encode_by_ref(
+            &self,
+            args: &mut Vec<SqliteArgumentValue<'q>>,
+        ) -> Result<IsNull, BoxDynError> {
+            let bytes = self.0.id().to_bytes();
+            args.push(SqliteArgumentValue::Blob(Cow::Owned(bytes.to_vec())));
+            Ok(IsNull::No)
+        }
+    }
+
+    impl<T> Decode<'_, Sqlite> for BlobId<T>
+    where
+        T: TryFrom<Id>,
+        T::Error: Into<BoxDynError>,
+    {
+        fn decode(value: SqliteValueRef<'_>) -> Result<Self, BoxDynError> {
+            T::try_from(<Id as Decode<Sqlite>>::decode(value)?)
+                .map(Self)
                 .map_err(Into::into)
         }
     }
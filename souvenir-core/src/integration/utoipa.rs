@@ -0,0 +1,122 @@
+use crate::id::Id;
+use crate::integration::schema::{
+    EXAMPLE, MAX_LENGTH, MIN_LENGTH, PREFIX_EXAMPLE, SUFFIX_EXAMPLE, id_pattern, prefix_pattern,
+    suffix_pattern, typed_example, typed_length, typed_name, typed_pattern,
+};
+use crate::prefix::Prefix;
+use crate::suffix::Suffix;
+use crate::tagged::Tagged;
+use crate::typed::TypedId;
+use alloc::borrow::Cow;
+use alloc::format;
+use utoipa::openapi::RefOr;
+use utoipa::openapi::schema::{ObjectBuilder, Schema, Type};
+use utoipa::{PartialSchema, ToSchema};
+
+impl PartialSchema for Id {
+    fn schema() -> RefOr<Schema> {
+        ObjectBuilder::new()
+            .schema_type(Type::String)
+            .description(Some("A prefixed identifier"))
+            .pattern(Some(id_pattern()))
+            .min_length(Some(MIN_LENGTH))
+            .max_length(Some(MAX_LENGTH))
+            .examples([EXAMPLE])
+            .into()
+    }
+}
+
+impl ToSchema for Id {
+    fn name() -> Cow<'static, str> {
+        "Id".into()
+    }
+}
+
+impl<T: Tagged> PartialSchema for TypedId<T> {
+    fn schema() -> RefOr<Schema> {
+        ObjectBuilder::new()
+            .schema_type(Type::String)
+            .description(Some(format!(
+                "A prefixed identifier with the prefix `{}`",
+                T::PREFIX
+            )))
+            .pattern(Some(typed_pattern::<T>()))
+            .min_length(Some(typed_length::<T>()))
+            .max_length(Some(typed_length::<T>()))
+            .examples([typed_example::<T>()])
+            .into()
+    }
+}
+
+impl<T: Tagged> ToSchema for TypedId<T> {
+    fn name() -> Cow<'static, str> {
+        typed_name::<T>().into()
+    }
+}
+
+impl PartialSchema for Prefix {
+    fn schema() -> RefOr<Schema> {
+        ObjectBuilder::new()
+            .schema_type(Type::String)
+            .description(Some("The prefix of an identifier"))
+            .pattern(Some(prefix_pattern()))
+            .min_length(Some(1))
+            .max_length(Some(4))
+            .examples([PREFIX_EXAMPLE])
+            .into()
+    }
+}
+
+impl ToSchema for Prefix {
+    fn name() -> Cow<'static, str> {
+        "Prefix".into()
+    }
+}
+
+impl PartialSchema for Suffix {
+    fn schema() -> RefOr<Schema> {
+        ObjectBuilder::new()
+            .schema_type(Type::String)
+            .description(Some("The suffix of an identifier"))
+            .pattern(Some(suffix_pattern()))
+            .min_length(Some(22))
+            .max_length(Some(22))
+            .examples([SUFFIX_EXAMPLE])
+            .into()
+    }
+}
+
+impl ToSchema for Suffix {
+    fn name() -> Cow<'static, str> {
+        "Suffix".into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{prefix::Prefix, tagged::Tagged, typed::TypedId};
+    use utoipa::openapi::RefOr;
+    use utoipa::openapi::schema::Schema;
+    use utoipa::{PartialSchema, ToSchema};
+
+    struct User;
+
+    impl Tagged for User {
+        const PREFIX: Prefix = Prefix::from_str_const("user");
+    }
+
+    #[test]
+    fn typed() {
+        let RefOr::T(Schema::Object(schema)) = TypedId::<User>::schema() else {
+            panic!("expected an object schema");
+        };
+
+        assert_eq!("UserId", TypedId::<User>::name());
+        assert_eq!(
+            Some("^user_[0-7][0-9a-hjkmnp-tv-z]{21}$"),
+            schema.pattern.as_deref()
+        );
+        assert_eq!(Some(27), schema.min_length);
+        assert_eq!(Some(27), schema.max_length);
+    }
+}
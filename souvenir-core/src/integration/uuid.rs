@@ -0,0 +1,144 @@
+use crate::{error::Error, id::Id, tagged::Tagged, typed::TypedId};
+use uuid::Uuid;
+
+impl Id {
+    /// Create an [`Id`] from a [`Uuid`] with the same bytes. If the top 20
+    /// bits of the [`Uuid`] are not a valid prefix, this method will error.
+    pub fn from_uuid(value: Uuid) -> Result<Self, Error> {
+        Self::from_bytes(value.into_bytes())
+    }
+
+    /// Convert this [`Id`] into a [`Uuid`] with the same bytes.
+    ///
+    /// The resulting [`Uuid`] does not necessarily have valid RFC 9562
+    /// version or variant bits. Use [`Id::to_uuid_v8`] if those are required.
+    pub const fn to_uuid(self) -> Uuid {
+        Uuid::from_bytes(self.to_bytes())
+    }
+
+    /// Convert this [`Id`] into an RFC 9562 version 8 (custom) [`Uuid`].
+    ///
+    /// The version and variant bits overwrite 6 bits of the suffix, so this
+    /// conversion is lossy unless those bits already hold the expected
+    /// values, which can be checked with [`Id::is_uuid_v8`].
+    ///
+    /// For time-ordered identifiers, such as those from
+    /// [`Suffix::from_timestamp`](crate::suffix::Suffix::from_timestamp) or
+    /// an `IdGenerator`, these bits are part of the timestamp: the version
+    /// replaces bits 16-19 and the variant replaces bits 2-3 of the
+    /// millisecond timestamp. Converting back gives a timestamp which may
+    /// differ by up to about 17 minutes, and identifiers may no longer sort
+    /// in the order they were generated.
+    ///
+    /// ```
+    /// # use souvenir_core::id::Id;
+    /// let id = Id::parse("user_02v58c5a3fy30k560qrtg4").unwrap();
+    /// let uuid = id.to_uuid_v8();
+    ///
+    /// assert_eq!(Some(uuid::Version::Custom), uuid.get_version());
+    /// assert_eq!(uuid::Variant::RFC4122, uuid.get_variant());
+    /// assert_eq!(id.prefix(), Id::from_uuid(uuid).unwrap().prefix());
+    /// ```
+    pub const fn to_uuid_v8(self) -> Uuid {
+        let mut bytes = self.to_bytes();
+        bytes[6] = (bytes[6] & 0x0f) | 0x80;
+        bytes[8] = (bytes[8] & 0x3f) | 0x80;
+
+        Uuid::from_bytes(bytes)
+    }
+
+    /// Check whether this [`Id`] already has RFC 9562 version 8 and variant
+    /// bits, meaning that [`Id::to_uuid_v8`] is lossless.
+    pub fn is_uuid_v8(self) -> bool {
+        self.to_uuid_v8() == self.to_uuid()
+    }
+}
+
+impl<T: Tagged> TypedId<T> {
+    /// Create a [`TypedId`] from a [`Uuid`] with the same bytes. If the
+    /// [`Uuid`] does not contain the prefix of `T`, this method will error.
+    pub fn from_uuid(value: Uuid) -> Result<Self, Error> {
+        Self::from_bytes(value.into_bytes())
+    }
+
+    /// Convert this [`TypedId`] into a [`Uuid`] with the same bytes.
+    pub const fn to_uuid(self) -> Uuid {
+        self.to_id().to_uuid()
+    }
+
+    /// Convert this [`TypedId`] into an RFC 9562 version 8 (custom)
+    /// [`Uuid`]. See [`Id::to_uuid_v8`].
+    pub const fn to_uuid_v8(self) -> Uuid {
+        self.to_id().to_uuid_v8()
+    }
+}
+
+impl From<Id> for Uuid {
+    fn from(value: Id) -> Self {
+        value.to_uuid()
+    }
+}
+
+impl TryFrom<Uuid> for Id {
+    type Error = Error;
+
+    fn try_from(value: Uuid) -> Result<Self, Self::Error> {
+        Self::from_uuid(value)
+    }
+}
+
+impl<T: Tagged> From<TypedId<T>> for Uuid {
+    fn from(value: TypedId<T>) -> Self {
+        value.to_uuid()
+    }
+}
+
+impl<T: Tagged> TryFrom<Uuid> for TypedId<T> {
+    type Error = Error;
+
+    fn try_from(value: Uuid) -> Result<Self, Self::Error> {
+        Self::from_uuid(value)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{error::Error, id::Id, prefix::Prefix, suffix::Suffix};
+    use uuid::Uuid;
+
+    #[test]
+    fn round_trip() {
+        let id = Id::parse("user_02v58c5a3fy30k560qrtg4").unwrap();
+        let uuid = Uuid::from(id);
+
+        assert_eq!(id.to_bytes(), uuid.into_bytes());
+        assert_eq!(Ok(id), Id::try_from(uuid));
+    }
+
+    #[test]
+    fn reject_invalid_prefix() {
+        assert_eq!(Err(Error::InvalidData), Id::try_from(Uuid::nil()));
+        assert_eq!(Err(Error::InvalidData), Id::try_from(Uuid::max()));
+    }
+
+    #[test]
+    fn v8_bits() {
+        let id = Id::parse("user_02v58c5a3fy30k560qrtg4").unwrap();
+        let v8 = Id::from_uuid(id.to_uuid_v8()).unwrap();
+
+        assert!(!id.is_uuid_v8());
+        assert!(v8.is_uuid_v8());
+        assert_eq!(v8.to_uuid(), v8.to_uuid_v8());
+    }
+
+    #[test]
+    fn v8_timestamp() {
+        let prefix = Prefix::parse("user").unwrap();
+        let id = Id::new(prefix, Suffix::from_timestamp(1_700_000_000_000, 0));
+        let v8 = Id::from_uuid(id.to_uuid_v8()).unwrap();
+
+        assert_ne!(id.timestamp(), v8.timestamp());
+        assert!(id.timestamp().abs_diff(v8.timestamp()) < 1 << 20);
+        assert_eq!(id.timestamp() & !0xf000c, v8.timestamp() & !0xf000c);
+    }
+}
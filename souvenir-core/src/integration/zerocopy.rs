@@ -0,0 +1,138 @@
+use crate::{
+    encoding::validate_id,
+    error::{Error, Result},
+    id::{Id, IdBytes},
+    tagged::Tagged,
+    typed::TypedId,
+};
+use zerocopy::FromBytes;
+
+impl Id {
+    /// Reinterpret 16 bytes as an [`Id`] without copying. If the bytes do
+    /// not form a valid [`Id`], this method will error.
+    ///
+    /// `Id` cannot implement [`zerocopy::TryFromBytes`], since that trait
+    /// can only be derived and not every bit pattern is a valid prefix.
+    pub fn try_ref_from_bytes(bytes: &[u8]) -> Result<&Self> {
+        let bytes = IdBytes::ref_from_bytes(bytes).map_err(|_| Error::InvalidData)?;
+        validate_id(*bytes)?;
+
+        // SAFETY: `Id` is a transparent wrapper around `[u8; 16]`, and the
+        // bytes were validated above.
+        Ok(unsafe { &*(bytes as *const IdBytes).cast::<Self>() })
+    }
+
+    /// Reinterpret a buffer of packed 16-byte identifiers, such as a
+    /// memory-mapped index file, as a slice of [`Id`]s without copying.
+    ///
+    /// If the length of the buffer is not a multiple of 16, or any of the
+    /// identifiers are invalid, this method will error.
+    ///
+    /// ```
+    /// # use souvenir_core::id::Id;
+    /// let id = Id::parse("user_02v58c5a3fy30k560qrtg4").unwrap();
+    /// let buffer = [id.to_bytes(), id.to_bytes()].concat();
+    ///
+    /// assert_eq!(Ok(&[id, id][..]), Id::try_slice_from_bytes(&buffer));
+    /// assert!(Id::try_slice_from_bytes(&buffer[1..]).is_err());
+    /// ```
+    pub fn try_slice_from_bytes(bytes: &[u8]) -> Result<&[Self]> {
+        let slice = cast_slice(bytes, |bytes| validate_id(bytes).map(drop))?;
+
+        // SAFETY: `Id` is a transparent wrapper around `[u8; 16]`, and every
+        // element was validated above.
+        Ok(unsafe { &*(slice as *const [IdBytes] as *const [Self]) })
+    }
+}
+
+impl<T: Tagged> TypedId<T> {
+    /// Reinterpret 16 bytes as a [`TypedId`] without copying. If the bytes
+    /// do not form a valid [`Id`] with the correct prefix, this method will
+    /// error.
+    pub fn try_ref_from_bytes(bytes: &[u8]) -> Result<&Self> {
+        let id = Id::try_ref_from_bytes(bytes)?;
+        Self::from_id(*id)?;
+
+        // SAFETY: `TypedId` is a transparent wrapper around `Id`, and the
+        // prefix was checked above.
+        Ok(unsafe { &*(id as *const Id).cast::<Self>() })
+    }
+
+    /// Reinterpret a buffer of packed 16-byte identifiers as a slice of
+    /// [`TypedId`]s without copying.
+    ///
+    /// If the length of the buffer is not a multiple of 16, or any of the
+    /// identifiers are invalid or have a different prefix, this method will
+    /// error.
+    pub fn try_slice_from_bytes(bytes: &[u8]) -> Result<&[Self]> {
+        let slice = cast_slice(bytes, |bytes| {
+            validate_id(bytes).and_then(Self::from_id).map(drop)
+        })?;
+
+        // SAFETY: `TypedId` is a transparent wrapper around `Id`, which is a
+        // transparent wrapper around `[u8; 16]`, and every element was
+        // validated above.
+        Ok(unsafe { &*(slice as *const [IdBytes] as *const [Self]) })
+    }
+}
+
+/// Split a buffer into 16-byte chunks, checking each of them with
+/// `validate`.
+fn cast_slice(bytes: &[u8], validate: impl Fn(IdBytes) -> Result<()>) -> Result<&[IdBytes]> {
+    let slice = <[IdBytes]>::ref_from_bytes(bytes).map_err(|_| Error::InvalidData)?;
+    slice.iter().try_for_each(|bytes| validate(*bytes))?;
+
+    Ok(slice)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{error::Error, id::Id, prefix::Prefix, tagged::Tagged, typed::TypedId};
+    use zerocopy::IntoBytes;
+
+    struct User;
+
+    impl Tagged for User {
+        const PREFIX: Prefix = Prefix::from_str_const("user");
+    }
+
+    #[test]
+    fn cast() {
+        let user = Id::parse("user_02v58c5a3fy30k560qrtg4").unwrap();
+        let org = Id::parse("org_02v58c5a3fy30k560qrtg4").unwrap();
+        let ids = [user, org];
+
+        let bytes = ids.as_bytes();
+        assert_eq!([user.to_bytes(), org.to_bytes()].as_flattened(), bytes);
+        assert_eq!(Ok(&ids[..]), Id::try_slice_from_bytes(bytes));
+        assert_eq!(Ok(&user), Id::try_ref_from_bytes(&bytes[..16]));
+
+        assert_eq!(
+            Err(Error::InvalidData),
+            Id::try_ref_from_bytes(&bytes[..15])
+        );
+        assert_eq!(
+            Err(Error::InvalidData),
+            Id::try_slice_from_bytes(&bytes[1..])
+        );
+        assert!(Id::try_slice_from_bytes(&[user.to_bytes(), [0; 16]].concat()).is_err());
+    }
+
+    #[test]
+    fn typed() {
+        let user = TypedId::<User>::parse("user_02v58c5a3fy30k560qrtg4").unwrap();
+        let org = Id::parse("org_02v58c5a3fy30k560qrtg4").unwrap();
+
+        assert_eq!(
+            Ok(&[user, user][..]),
+            TypedId::<User>::try_slice_from_bytes([user, user].as_bytes())
+        );
+        assert_eq!(
+            Err(Error::PrefixMismatch {
+                expected: User::PREFIX,
+                found: org.prefix()
+            }),
+            TypedId::<User>::try_ref_from_bytes(org.as_bytes())
+        );
+    }
+}
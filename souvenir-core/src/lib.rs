@@ -3,12 +3,41 @@
 //! This crate contains implementation details for
 //! [`souvenir`](https://docs.rs/souvenir/latest/souvenir/).
 //! This crate is not intended to be used directly.
+//!
+//! This crate is `no_std` unless the `std` feature is enabled. Parsing,
+//! formatting and validation are always available, while the `alloc` feature
+//! enables APIs returning a [`String`](alloc::string::String).
+
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
 
+#[cfg(any(
+    all(feature = "sqlite", any(feature = "diesel", feature = "sqlx")),
+    feature = "rusqlite"
+))]
+pub mod blob;
+pub mod clock;
 pub mod encoding;
 pub mod error;
+#[cfg(any(feature = "actix-web", feature = "axum"))]
+pub mod extract;
+#[cfg(all(feature = "rand", feature = "std"))]
+pub mod generator;
 pub mod id;
 pub mod identifiable;
 pub mod integration;
+pub mod long;
+pub mod options;
 pub mod prefix;
+#[cfg(feature = "alloc")]
+pub mod registry;
+#[cfg(feature = "serde")]
+pub mod serde;
+pub mod string;
 pub mod suffix;
 pub mod tagged;
+#[cfg(feature = "sea-orm")]
+pub mod text;
+pub mod typed;
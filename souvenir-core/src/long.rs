@@ -0,0 +1,232 @@
+use crate::encoding::{
+    decode_long_id, decode_long_prefix, encode_long_id_into, encode_long_prefix_into,
+    validate_long_id, validate_long_prefix,
+};
+use crate::error::{Error, Result};
+use crate::id::IdBytes;
+use core::fmt::{Debug, Display, Formatter};
+use core::str::FromStr;
+
+/// A valid [`LongId`] prefix.
+///
+/// Unlike a [`Prefix`](crate::prefix::Prefix), a [`LongPrefix`] can be 1-12
+/// characters long, and can contain the characters `a`-`z` and `0`-`9`. The
+/// first character must always be a letter.
+#[derive(Copy, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct LongPrefix(u64);
+
+impl LongPrefix {
+    /// The maximum number of characters in a [`LongPrefix`].
+    pub const MAX_LENGTH: usize = 12;
+
+    /// Create a [`LongPrefix`] from its inner [`u64`] value.
+    /// If the provided value is not valid, this will error.
+    pub fn new(value: u64) -> Result<Self> {
+        validate_long_prefix(value)
+    }
+
+    /// Create a [`LongPrefix`] from its inner [`u64`] value.
+    ///
+    /// # Safety
+    /// This is potentially unsafe as the rest of the API assumes that the
+    /// inner value is valid.
+    pub const unsafe fn new_unchecked(value: u64) -> Self {
+        Self(value)
+    }
+
+    /// Retrieve the inner [`u64`] value from this [`LongPrefix`].
+    pub const fn to_u64(self) -> u64 {
+        self.0
+    }
+
+    /// Attempt to parse the provided string into a [`LongPrefix`]
+    pub fn parse(prefix: &str) -> Result<Self> {
+        decode_long_prefix(prefix)
+    }
+}
+
+impl Debug for LongPrefix {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{self}")
+    }
+}
+
+impl Display for LongPrefix {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let mut buf = [0; Self::MAX_LENGTH];
+        let size = encode_long_prefix_into(*self, &mut buf);
+
+        // UNSAFE: All bytes are guaranteed to be in ASCII range.
+        f.pad(unsafe { core::str::from_utf8_unchecked(&buf[..size]) })
+    }
+}
+
+impl FromStr for LongPrefix {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::parse(s)
+    }
+}
+
+impl From<LongPrefix> for u64 {
+    fn from(value: LongPrefix) -> Self {
+        value.0
+    }
+}
+
+impl TryFrom<u64> for LongPrefix {
+    type Error = Error;
+
+    fn try_from(value: u64) -> Result<Self> {
+        Self::new(value)
+    }
+}
+
+/// A 128-bit identifier consisting of a 1-12 character tag and 64 random
+/// bits.
+///
+/// A [`LongId`] trades suffix entropy for a longer, more descriptive prefix.
+/// The upper 64 bits store the prefix, and the lower 64 bits store the
+/// suffix, which is encoded as 13 characters. With only 64 random bits,
+/// there is a 50% chance of a collision after generating around 2^32
+/// (~4.3 billion) identifiers with the same prefix, compared to around 2^54
+/// for an [`Id`](crate::id::Id).
+///
+/// [`LongId`]s use a different layout from [`Id`](crate::id::Id)s, and the
+/// two types cannot be converted into one another.
+///
+/// ```
+/// # use souvenir_core::long::LongId;
+/// let id = LongId::parse("invoice2_0h8cm5a6rx0tc").unwrap();
+///
+/// assert_eq!(id.prefix().to_string(), "invoice2");
+/// assert_eq!(id.to_string(), "invoice2_0h8cm5a6rx0tc");
+/// ```
+#[derive(Copy, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(transparent)]
+pub struct LongId(IdBytes);
+
+impl LongId {
+    /// Create a new [`LongId`] with the provided prefix and suffix.
+    pub const fn new(prefix: LongPrefix, suffix: u64) -> Self {
+        let value = ((prefix.to_u64() as u128) << 64) | suffix as u128;
+        Self(value.to_be_bytes())
+    }
+
+    /// Create a new [`LongId`] with the following bytes. If the provided
+    /// bytes do not form a valid [`LongId`], this method will error.
+    pub fn from_bytes(value: IdBytes) -> Result<Self> {
+        validate_long_id(value)
+    }
+
+    /// Create a new [`LongId`] with the provided raw value.
+    /// The value is not checked to be a valid [`LongId`].
+    ///
+    /// # Safety
+    /// This method is unsafe because the API assumes that the provided value
+    /// is valid in order to provide memory safety.
+    pub const unsafe fn from_bytes_unchecked(value: IdBytes) -> Self {
+        Self(value)
+    }
+
+    /// Get the data value of the identifier.
+    pub fn as_bytes(&self) -> &IdBytes {
+        &self.0
+    }
+
+    /// Get the data value of the identifier.
+    pub const fn to_bytes(self) -> IdBytes {
+        self.0
+    }
+
+    /// Get the data value of the identifier as a [`u128`].
+    pub const fn to_u128(self) -> u128 {
+        u128::from_be_bytes(self.0)
+    }
+
+    /// Get the prefix of this identifier.
+    pub const fn prefix(self) -> LongPrefix {
+        unsafe { LongPrefix::new_unchecked((self.to_u128() >> 64) as u64) }
+    }
+
+    /// Get the suffix of this identifier.
+    pub const fn suffix(self) -> u64 {
+        self.to_u128() as u64
+    }
+
+    /// Cast this [`LongId`] into a [`LongId`] with a different prefix.
+    pub const fn cast(self, prefix: LongPrefix) -> Self {
+        Self::new(prefix, self.suffix())
+    }
+
+    /// Test to see if the provided string is a valid [`LongId`].
+    pub fn test(value: &str) -> bool {
+        Self::parse(value).is_ok()
+    }
+
+    /// Attempt to parse the provided string into a [`LongId`].
+    pub fn parse(value: &str) -> Result<Self> {
+        decode_long_id(value)
+    }
+}
+
+impl Debug for LongId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{self}")
+    }
+}
+
+impl Display for LongId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let mut buf = [0; LongPrefix::MAX_LENGTH + 14];
+        let size = encode_long_id_into(*self, &mut buf);
+
+        // UNSAFE: All bytes are guaranteed to be in ASCII range.
+        f.pad(unsafe { core::str::from_utf8_unchecked(&buf[..size]) })
+    }
+}
+
+impl FromStr for LongId {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::parse(s)
+    }
+}
+
+impl From<LongId> for u128 {
+    fn from(value: LongId) -> Self {
+        value.to_u128()
+    }
+}
+
+impl From<LongId> for IdBytes {
+    fn from(value: LongId) -> Self {
+        value.to_bytes()
+    }
+}
+
+impl TryFrom<u128> for LongId {
+    type Error = Error;
+
+    fn try_from(value: u128) -> Result<Self> {
+        Self::from_bytes(value.to_be_bytes())
+    }
+}
+
+impl TryFrom<IdBytes> for LongId {
+    type Error = Error;
+
+    fn try_from(value: IdBytes) -> Result<Self> {
+        Self::from_bytes(value)
+    }
+}
+
+impl TryFrom<&[u8]> for LongId {
+    type Error = Error;
+
+    fn try_from(value: &[u8]) -> Result<Self> {
+        Self::from_bytes(value.try_into().map_err(|_| Error::InvalidData)?)
+    }
+}
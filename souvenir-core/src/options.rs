@@ -0,0 +1,59 @@
+/// Options controlling how strictly identifiers are parsed.
+///
+/// The default options are strict, and only accept the canonical string
+/// representation of an identifier. The lenient options follow
+/// [Crockford Base 32](https://www.crockford.com/base32.html) more closely,
+/// accepting uppercase input and mapping `i`/`l` to `1` and `o` to `0` in
+/// the suffix.
+///
+/// ```
+/// # use souvenir_core::{id::Id, options::ParseOptions};
+/// let id = Id::parse_lenient("USER_O2V58C5A3FY3OK56OQRTG4").unwrap();
+/// assert_eq!(id.to_string(), "user_02v58c5a3fy30k560qrtg4");
+///
+/// let options = ParseOptions::strict().case_insensitive(true);
+/// assert!(Id::parse_with("USER_02V58C5A3FY30K560QRTG4", options).is_ok());
+/// assert!(Id::parse_with("USER_O2V58C5A3FY3OK56OQRTG4", options).is_err());
+/// ```
+#[derive(Copy, Clone, Debug, Default, Hash, PartialEq, Eq)]
+pub struct ParseOptions {
+    pub(crate) case_insensitive: bool,
+    pub(crate) normalize: bool,
+    pub(crate) checksum: bool,
+}
+
+impl ParseOptions {
+    /// Only accept the canonical string representation.
+    pub const fn strict() -> Self {
+        Self {
+            case_insensitive: false,
+            normalize: false,
+            checksum: false,
+        }
+    }
+
+    /// Accept uppercase input, and normalize ambiguous suffix characters.
+    pub const fn lenient() -> Self {
+        Self::strict().case_insensitive(true).normalize(true)
+    }
+
+    /// Set whether uppercase characters are accepted.
+    pub const fn case_insensitive(self, case_insensitive: bool) -> Self {
+        Self {
+            case_insensitive,
+            ..self
+        }
+    }
+
+    /// Set whether `i` and `l` are read as `1`, and `o` is read as `0` in
+    /// the suffix.
+    pub const fn normalize(self, normalize: bool) -> Self {
+        Self { normalize, ..self }
+    }
+
+    /// Set whether the input is expected to end with a check symbol.
+    /// See [`Id::to_checked_string`](crate::id::Id::to_checked_string).
+    pub const fn checksum(self, checksum: bool) -> Self {
+        Self { checksum, ..self }
+    }
+}
@@ -1,11 +1,14 @@
-use std::{
+use core::{
     fmt::{Debug, Display},
     str::FromStr,
 };
 
 use crate::{
-    encoding::{decode_prefix, encode_prefix, validate_prefix},
+    encoding::{
+        decode_prefix, decode_prefix_const, decode_prefix_with, encode_prefix_into, validate_prefix,
+    },
     error::{Error, Result},
+    options::ParseOptions,
 };
 
 /// A valid [`Id`](crate::id::Id) prefix.
@@ -15,7 +18,7 @@ pub struct Prefix(u32);
 impl Prefix {
     /// Create a [`Prefix`] from its inner [`u32`] value.
     /// If the provided value is not valid, this will error.
-    pub fn new(value: u32) -> Result<Self> {
+    pub const fn new(value: u32) -> Result<Self> {
         validate_prefix(value)
     }
 
@@ -37,6 +40,36 @@ impl Prefix {
     pub fn parse(prefix: &str) -> Result<Self> {
         decode_prefix(prefix)
     }
+
+    /// Attempt to parse the provided string into a [`Prefix`] using the
+    /// provided [`ParseOptions`].
+    pub fn parse_with(prefix: &str, options: ParseOptions) -> Result<Self> {
+        decode_prefix_with(prefix, options)
+    }
+
+    /// Parse the provided string into a [`Prefix`] in a `const` context.
+    ///
+    /// # Panics
+    /// Panics if the string is not a valid prefix. When evaluated in a
+    /// `const` item, this results in a compile error.
+    ///
+    /// ```
+    /// # use souvenir_core::prefix::Prefix;
+    /// const USER: Prefix = Prefix::from_str_const("user");
+    ///
+    /// assert_eq!(USER, Prefix::parse("user").unwrap());
+    /// ```
+    ///
+    /// ```compile_fail
+    /// # use souvenir_core::prefix::Prefix;
+    /// const USER: Prefix = Prefix::from_str_const("users");
+    /// ```
+    pub const fn from_str_const(prefix: &str) -> Self {
+        match decode_prefix_const(prefix.as_bytes()) {
+            Ok(prefix) => prefix,
+            Err(_) => panic!("invalid prefix: expected 1-4 characters in the range a-z"),
+        }
+    }
 }
 
 impl Default for Prefix {
@@ -46,21 +79,25 @@ impl Default for Prefix {
 }
 
 impl Debug for Prefix {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "{self}")
     }
 }
 
 impl Display for Prefix {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", encode_prefix(*self))
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut buf = [0; 4];
+        let size = encode_prefix_into(*self, &mut buf);
+
+        // UNSAFE: All bytes are guaranteed to be in ASCII range.
+        f.pad(unsafe { core::str::from_utf8_unchecked(&buf[..size]) })
     }
 }
 
 impl FromStr for Prefix {
     type Err = Error;
 
-    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+    fn from_str(s: &str) -> core::result::Result<Self, Self::Err> {
         Self::parse(s)
     }
 }
@@ -74,7 +111,7 @@ impl From<Prefix> for u32 {
 impl TryFrom<u32> for Prefix {
     type Error = Error;
 
-    fn try_from(value: u32) -> std::result::Result<Self, Self::Error> {
+    fn try_from(value: u32) -> core::result::Result<Self, Self::Error> {
         Self::new(value)
     }
 }
@@ -0,0 +1,377 @@
+use crate::error::{Error, Result};
+use crate::id::Id;
+use crate::identifiable::Identifiable;
+use crate::prefix::Prefix;
+use crate::tagged::Tagged;
+use alloc::borrow::Cow;
+use alloc::string::String;
+use core::cmp::Ordering;
+use core::fmt::{Debug, Display, Formatter};
+use core::hash::{Hash, Hasher};
+use core::marker::PhantomData;
+use core::str::FromStr;
+
+/// A known [`Prefix`], along with a human-readable name for its entity and
+/// the owner of that entity.
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct PrefixInfo {
+    prefix: Prefix,
+    name: Cow<'static, str>,
+    owner: Cow<'static, str>,
+}
+
+impl PrefixInfo {
+    /// Create a new [`PrefixInfo`] for the provided prefix.
+    pub const fn new(prefix: Prefix, name: &'static str, owner: &'static str) -> Self {
+        Self {
+            prefix,
+            name: Cow::Borrowed(name),
+            owner: Cow::Borrowed(owner),
+        }
+    }
+
+    /// Create a new [`PrefixInfo`] for the prefix of a [`Tagged`] type.
+    pub const fn tagged<T: Tagged>(name: &'static str, owner: &'static str) -> Self {
+        Self::new(T::PREFIX, name, owner)
+    }
+
+    /// Create a new [`PrefixInfo`] from owned strings, such as those loaded
+    /// from configuration at runtime.
+    pub fn owned(prefix: Prefix, name: String, owner: String) -> Self {
+        Self {
+            prefix,
+            name: Cow::Owned(name),
+            owner: Cow::Owned(owner),
+        }
+    }
+
+    /// Get the prefix.
+    pub const fn prefix(&self) -> Prefix {
+        self.prefix
+    }
+
+    /// Get the human-readable name of the entity identified by the prefix.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Get the owner of the entity identified by the prefix.
+    pub fn owner(&self) -> &str {
+        &self.owner
+    }
+}
+
+/// A set of known prefixes, used to reject identifiers with unknown tags.
+///
+/// A [`PrefixRegistry`] can be built at compile time from a static list of
+/// [`PrefixInfo`]s, or at runtime using [`PrefixRegistry::insert`].
+///
+/// ```
+/// # use souvenir_core::{id::Id, prefix::Prefix, registry::{PrefixInfo, PrefixRegistry}, tagged::Tagged};
+/// struct User;
+///
+/// impl Tagged for User {
+///     const PREFIX: Prefix = Prefix::from_str_const("user");
+/// }
+///
+/// static PREFIXES: &[PrefixInfo] = &[
+///     PrefixInfo::tagged::<User>("User", "accounts"),
+///     PrefixInfo::new(Prefix::from_str_const("org"), "Organization", "accounts"),
+/// ];
+///
+/// static REGISTRY: PrefixRegistry = PrefixRegistry::from_static(PREFIXES);
+///
+/// let id = REGISTRY.parse("user_02v58c5a3fy30k560qrtg4").unwrap();
+/// assert_eq!(REGISTRY.validate(id).unwrap().name(), "User");
+///
+/// assert!(REGISTRY.parse("team_02v58c5a3fy30k560qrtg4").is_err());
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PrefixRegistry {
+    entries: Cow<'static, [PrefixInfo]>,
+}
+
+impl PrefixRegistry {
+    /// Create an empty [`PrefixRegistry`].
+    pub const fn new() -> Self {
+        Self::from_static(&[])
+    }
+
+    /// Create a [`PrefixRegistry`] containing the provided entries. If a
+    /// prefix appears more than once, the first entry is used.
+    pub const fn from_static(entries: &'static [PrefixInfo]) -> Self {
+        Self {
+            entries: Cow::Borrowed(entries),
+        }
+    }
+
+    /// Add an entry to this [`PrefixRegistry`], returning the previous entry
+    /// with the same prefix if there was one.
+    pub fn insert(&mut self, info: PrefixInfo) -> Option<PrefixInfo> {
+        let entries = self.entries.to_mut();
+
+        match entries.iter_mut().find(|entry| entry.prefix == info.prefix) {
+            Some(entry) => Some(core::mem::replace(entry, info)),
+            None => {
+                entries.push(info);
+                None
+            }
+        }
+    }
+
+    /// Add an entry to this [`PrefixRegistry`], replacing any previous entry
+    /// with the same prefix.
+    pub fn with(mut self, info: PrefixInfo) -> Self {
+        self.insert(info);
+        self
+    }
+
+    /// Get the entry for the provided prefix, if it is known.
+    pub fn get(&self, prefix: Prefix) -> Option<&PrefixInfo> {
+        self.entries.iter().find(|entry| entry.prefix == prefix)
+    }
+
+    /// Check whether the provided prefix is known.
+    pub fn contains(&self, prefix: Prefix) -> bool {
+        self.get(prefix).is_some()
+    }
+
+    /// Get an iterator over the entries in this [`PrefixRegistry`].
+    pub fn iter(&self) -> impl Iterator<Item = &PrefixInfo> {
+        self.entries.iter()
+    }
+
+    /// Get the number of entries in this [`PrefixRegistry`].
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Check whether this [`PrefixRegistry`] has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Get the entry for the prefix of the provided [`Id`]. If the prefix is
+    /// not known, this will error.
+    pub fn validate(&self, id: Id) -> Result<&PrefixInfo> {
+        let found = id.prefix();
+        self.get(found).ok_or(Error::UnknownPrefix { found })
+    }
+
+    /// Attempt to parse the provided string into an [`Id`] with a known
+    /// prefix.
+    pub fn parse(&self, value: &str) -> Result<Id> {
+        let id = Id::parse(value)?;
+        self.validate(id).map(|_| id)
+    }
+}
+
+impl Extend<PrefixInfo> for PrefixRegistry {
+    fn extend<I: IntoIterator<Item = PrefixInfo>>(&mut self, iter: I) {
+        for info in iter {
+            self.insert(info);
+        }
+    }
+}
+
+impl FromIterator<PrefixInfo> for PrefixRegistry {
+    fn from_iter<I: IntoIterator<Item = PrefixInfo>>(iter: I) -> Self {
+        let mut registry = Self::new();
+        registry.extend(iter);
+        registry
+    }
+}
+
+/// A type which provides a global [`PrefixRegistry`], used by [`KnownId`].
+pub trait Registry {
+    /// Get the registry.
+    fn registry() -> &'static PrefixRegistry;
+}
+
+/// An [`Id`] whose prefix is known to the [`PrefixRegistry`] of `R`.
+///
+/// The prefix is checked whenever a [`KnownId`] is parsed or deserialized.
+///
+/// ```
+/// # use souvenir_core::{prefix::Prefix, registry::{KnownId, PrefixInfo, PrefixRegistry, Registry}};
+/// struct Api;
+///
+/// impl Registry for Api {
+///     fn registry() -> &'static PrefixRegistry {
+///         static PREFIXES: &[PrefixInfo] = &[
+///             PrefixInfo::new(Prefix::from_str_const("user"), "User", "accounts"),
+///         ];
+///         static REGISTRY: PrefixRegistry = PrefixRegistry::from_static(PREFIXES);
+///
+///         &REGISTRY
+///     }
+/// }
+///
+/// let id: KnownId<Api> = "user_02v58c5a3fy30k560qrtg4".parse().unwrap();
+/// assert_eq!(id.info().owner(), "accounts");
+///
+/// assert!("team_02v58c5a3fy30k560qrtg4".parse::<KnownId<Api>>().is_err());
+/// ```
+#[repr(transparent)]
+pub struct KnownId<R: Registry> {
+    id: Id,
+    marker: PhantomData<fn() -> R>,
+}
+
+impl<R: Registry> KnownId<R> {
+    /// Create a [`KnownId`] from an [`Id`]. If the prefix of the provided
+    /// [`Id`] is not known, this will error.
+    pub fn from_id(id: Id) -> Result<Self> {
+        R::registry().validate(id)?;
+
+        Ok(Self {
+            id,
+            marker: PhantomData,
+        })
+    }
+
+    /// Attempt to parse the provided string into a [`KnownId`].
+    pub fn parse(value: &str) -> Result<Self> {
+        Id::parse(value).and_then(Self::from_id)
+    }
+
+    /// Get a reference to the underlying [`Id`].
+    pub fn as_id(&self) -> &Id {
+        &self.id
+    }
+
+    /// Get the underlying [`Id`].
+    pub const fn to_id(self) -> Id {
+        self.id
+    }
+
+    /// Get the registry entry for the prefix of this identifier.
+    pub fn info(&self) -> &'static PrefixInfo {
+        R::registry()
+            .get(self.id.prefix())
+            .expect("prefix was validated")
+    }
+}
+
+impl<R: Registry> Copy for KnownId<R> {}
+
+impl<R: Registry> Clone for KnownId<R> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<R: Registry> PartialEq for KnownId<R> {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl<R: Registry> Eq for KnownId<R> {}
+
+impl<R: Registry> PartialOrd for KnownId<R> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<R: Registry> Ord for KnownId<R> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.id.cmp(&other.id)
+    }
+}
+
+impl<R: Registry> Hash for KnownId<R> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.id.hash(state)
+    }
+}
+
+impl<R: Registry> Debug for KnownId<R> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{self}")
+    }
+}
+
+impl<R: Registry> Display for KnownId<R> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(&self.id, f)
+    }
+}
+
+impl<R: Registry> FromStr for KnownId<R> {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::parse(s)
+    }
+}
+
+impl<R: Registry> Identifiable for KnownId<R> {
+    fn id(&self) -> Id {
+        self.id
+    }
+}
+
+impl<R: Registry> From<KnownId<R>> for Id {
+    fn from(value: KnownId<R>) -> Self {
+        value.id
+    }
+}
+
+impl<R: Registry> TryFrom<Id> for KnownId<R> {
+    type Error = Error;
+
+    fn try_from(value: Id) -> Result<Self> {
+        Self::from_id(value)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        error::Error,
+        id::Id,
+        prefix::Prefix,
+        registry::{PrefixInfo, PrefixRegistry},
+        suffix::Suffix,
+    };
+    use rand::random;
+
+    const USER: Prefix = Prefix::from_str_const("user");
+    const ORG: Prefix = Prefix::from_str_const("org");
+
+    static PREFIXES: &[PrefixInfo] = &[PrefixInfo::new(USER, "User", "accounts")];
+    static REGISTRY: PrefixRegistry = PrefixRegistry::from_static(PREFIXES);
+
+    #[test]
+    fn validate() {
+        let user = Id::new(USER, Suffix::new(random()));
+        let org = Id::new(ORG, Suffix::new(random()));
+
+        assert_eq!(Ok("User"), REGISTRY.validate(user).map(PrefixInfo::name));
+        assert_eq!(
+            Err(Error::UnknownPrefix { found: ORG }),
+            REGISTRY.validate(org)
+        );
+        assert_eq!(
+            Err(Error::UnknownPrefix { found: ORG }),
+            REGISTRY.parse(&org.to_string())
+        );
+    }
+
+    #[test]
+    fn insert() {
+        let mut registry = REGISTRY.clone();
+        let org = PrefixInfo::owned(ORG, "Organization".into(), "billing".into());
+
+        assert_eq!(None, registry.insert(org.clone()));
+        assert_eq!(
+            Some(org),
+            registry.insert(PrefixInfo::new(ORG, "Org", "teams"))
+        );
+        assert_eq!(Some("teams"), registry.get(ORG).map(PrefixInfo::owner));
+        assert_eq!(2, registry.len());
+        assert_eq!(1, REGISTRY.len());
+    }
+}
@@ -0,0 +1,10 @@
+//! Serialize an identifier using its default representation, but
+//! deserialize it from any representation supported by the other modules:
+//! a prefixed string, a UUID string, 32 hexadecimal digits, a 128-bit
+//! integer or 16 bytes.
+//!
+//! This requires a self-describing format, since
+//! [`Deserializer::deserialize_any`](serde::Deserializer::deserialize_any)
+//! is used.
+
+representation!(ANY);
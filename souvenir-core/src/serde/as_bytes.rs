@@ -0,0 +1,7 @@
+//! Serialize an identifier as its 16 bytes, regardless of
+//! [`Serializer::is_human_readable`](serde::Serializer::is_human_readable).
+//!
+//! Formats without a byte string type, such as JSON, use an array of numbers
+//! instead. Both are accepted when deserializing.
+
+representation!(BYTES);
@@ -0,0 +1,6 @@
+//! Serialize an identifier as 32 lowercase hexadecimal digits, such as
+//! `accb202d950c2a86ff0c1329817c6a04`.
+//!
+//! Uppercase digits are also accepted when deserializing.
+
+representation!(HEX);
@@ -0,0 +1,5 @@
+//! Serialize an identifier as its prefixed string, such as
+//! `user_02v58c5a3fy30k560qrtg4`, regardless of
+//! [`Serializer::is_human_readable`](serde::Serializer::is_human_readable).
+
+representation!(STRING);
@@ -0,0 +1,5 @@
+//! Serialize an identifier as a 128-bit integer.
+//!
+//! Not all formats support 128-bit integers.
+
+representation!(U128);
@@ -0,0 +1,6 @@
+//! Serialize an identifier as a hyphenated UUID string, such as
+//! `accb202d-950c-2a86-ff0c-1329817c6a04`.
+//!
+//! UUIDs without hyphens are also accepted when deserializing.
+
+representation!(UUID);
@@ -0,0 +1,31 @@
+//! Serialize an identifier as a BSON binary with the UUID subtype,
+//! regardless of [`Serializer::is_human_readable`].
+//!
+//! Any identifier which implements [`Identifiable`] and [`TryFrom<Id>`] is
+//! supported, such as an [`Id`] or a [`TypedId`](crate::typed::TypedId).
+//! Identifiers stored as strings are also accepted when deserializing.
+
+use crate::id::Id;
+use crate::identifiable::Identifiable;
+use bson::spec::BinarySubtype;
+use core::fmt::Display;
+use serde::{Deserializer, Serializer};
+
+/// Serialize an identifier as a BSON binary with the UUID subtype.
+pub fn serialize<T, S>(id: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: Identifiable,
+    S: Serializer,
+{
+    super::bson::serialize(id, BinarySubtype::Uuid, serializer)
+}
+
+/// Deserialize an identifier from a BSON binary or string.
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    T: TryFrom<Id>,
+    T::Error: Display,
+    D: Deserializer<'de>,
+{
+    super::bson::deserialize(deserializer)
+}
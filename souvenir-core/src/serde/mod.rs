@@ -0,0 +1,538 @@
+//! Modules for use with `#[serde(with = "...")]`, which override the default
+//! representation of identifiers.
+//!
+//! By default, identifiers are serialized as strings when
+//! [`Serializer::is_human_readable`] is `true`, and as bytes otherwise. The
+//! `as_*` modules always use one representation instead:
+//!
+//! - [`as_string`]: the prefixed string, such as `user_02v58c5a3fy30k560qrtg4`
+//! - [`as_bytes`]: the 16 bytes
+//! - [`as_u128`]: a 128-bit integer
+//! - [`as_uuid`]: a hyphenated UUID string
+//! - [`as_hex`]: 32 hexadecimal digits
+//!
+//! [`as_any`] serializes using the default representation, but deserializes
+//! from any of the above, which is useful while migrating between them. It
+//! requires a self-describing format, such as JSON or CBOR.
+//!
+//! Each module works with any identifier which implements [`Identifiable`]
+//! and [`TryFrom<Id>`], such as an [`Id`] or a
+//! [`TypedId`](crate::typed::TypedId), and has `option` and `vec` submodules
+//! for [`Option`] and [`Vec`] fields.
+//!
+//! ```text
+//! #[derive(Serialize, Deserialize)]
+//! struct Event {
+//!     #[serde(with = "souvenir::serde::as_uuid")]
+//!     id: Id,
+//!     #[serde(with = "souvenir::serde::as_string::option")]
+//!     parent: Option<TypedId<User>>,
+//!     #[serde(with = "souvenir::serde::as_any::vec")]
+//!     related: Vec<Id>,
+//! }
+//! ```
+
+use crate::id::Id;
+use crate::identifiable::Identifiable;
+use core::fmt::{Display, Formatter};
+use serde::de::{Error, IgnoredAny, SeqAccess, Unexpected, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+const STRING: u8 = 0;
+const BYTES: u8 = 1;
+const U128: u8 = 2;
+const UUID: u8 = 3;
+const HEX: u8 = 4;
+const ANY: u8 = 5;
+
+/// Generate the `serialize` and `deserialize` functions, along with the
+/// `option` and `vec` submodules, for one representation.
+macro_rules! representation {
+    ($form:ident) => {
+        use crate::id::Id;
+        use crate::identifiable::Identifiable;
+        use core::fmt::Display;
+        use serde::{Deserializer, Serializer};
+
+        /// Serialize an identifier.
+        pub fn serialize<T, S>(id: &T, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            T: Identifiable,
+            S: Serializer,
+        {
+            super::serialize::<{ super::$form }, T, S>(id, serializer)
+        }
+
+        /// Deserialize an identifier.
+        pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+        where
+            T: TryFrom<Id>,
+            T::Error: Display,
+            D: Deserializer<'de>,
+        {
+            super::deserialize::<{ super::$form }, T, D>(deserializer)
+        }
+
+        /// The same representation, for an [`Option`] of an identifier.
+        pub mod option {
+            use crate::id::Id;
+            use crate::identifiable::Identifiable;
+            use core::fmt::Display;
+            use serde::{Deserializer, Serializer};
+
+            /// Serialize an optional identifier.
+            pub fn serialize<T, S>(id: &Option<T>, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                T: Identifiable,
+                S: Serializer,
+            {
+                super::super::serialize_option::<{ super::super::$form }, T, S>(id, serializer)
+            }
+
+            /// Deserialize an optional identifier.
+            pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Option<T>, D::Error>
+            where
+                T: TryFrom<Id>,
+                T::Error: Display,
+                D: Deserializer<'de>,
+            {
+                super::super::deserialize_option::<{ super::super::$form }, T, D>(deserializer)
+            }
+        }
+
+        /// The same representation, for a [`Vec`] of identifiers.
+        pub mod vec {
+            use crate::id::Id;
+            use crate::identifiable::Identifiable;
+            use alloc::vec::Vec;
+            use core::fmt::Display;
+            use serde::{Deserializer, Serializer};
+
+            /// Serialize a list of identifiers.
+            pub fn serialize<T, S>(ids: &[T], serializer: S) -> Result<S::Ok, S::Error>
+            where
+                T: Identifiable,
+                S: Serializer,
+            {
+                super::super::serialize_vec::<{ super::super::$form }, T, S>(ids, serializer)
+            }
+
+            /// Deserialize a list of identifiers.
+            pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Vec<T>, D::Error>
+            where
+                T: TryFrom<Id>,
+                T::Error: Display,
+                D: Deserializer<'de>,
+            {
+                super::super::deserialize_vec::<{ super::super::$form }, T, D>(deserializer)
+            }
+        }
+    };
+}
+
+pub mod as_any;
+pub mod as_bytes;
+pub mod as_hex;
+pub mod as_string;
+pub mod as_u128;
+pub mod as_uuid;
+#[cfg(feature = "bson")]
+pub mod bson_binary;
+#[cfg(feature = "bson")]
+pub mod bson_uuid;
+
+/// An identifier which is (de)serialized using the representation `FORM`.
+struct As<const FORM: u8, T>(T);
+
+impl<const FORM: u8, T: Identifiable> Serialize for As<FORM, T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let id = self.0.id();
+
+        match FORM {
+            STRING => serializer.serialize_str(&id.encode()),
+            BYTES => serializer.serialize_bytes(id.as_bytes()),
+            U128 => serializer.serialize_u128(id.to_u128()),
+            UUID => serializer.serialize_str(ascii(&encode_uuid(id))),
+            HEX => serializer.serialize_str(ascii(&encode_hex(id))),
+            _ => id.serialize(serializer),
+        }
+    }
+}
+
+impl<'de, const FORM: u8, T> Deserialize<'de> for As<FORM, T>
+where
+    T: TryFrom<Id>,
+    T::Error: Display,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let id = match FORM {
+            STRING | UUID | HEX => deserializer.deserialize_str(IdVisitor::<FORM>),
+            BYTES => deserializer.deserialize_bytes(IdVisitor::<FORM>),
+            U128 => deserializer.deserialize_u128(IdVisitor::<FORM>),
+            _ => deserializer.deserialize_any(IdVisitor::<FORM>),
+        }?;
+
+        T::try_from(id).map(Self).map_err(Error::custom)
+    }
+}
+
+struct IdVisitor<const FORM: u8>;
+
+impl<'de, const FORM: u8> Visitor<'de> for IdVisitor<FORM> {
+    type Value = Id;
+
+    fn expecting(&self, f: &mut Formatter) -> core::fmt::Result {
+        f.write_str(match FORM {
+            STRING => "an identifier string",
+            BYTES => "16 bytes",
+            U128 => "a 128-bit integer",
+            UUID => "a UUID string",
+            HEX => "32 hexadecimal digits",
+            _ => "an identifier string, UUID string, hexadecimal string, integer or 16 bytes",
+        })
+    }
+
+    fn visit_str<E: Error>(self, v: &str) -> Result<Self::Value, E> {
+        let bytes = match FORM {
+            STRING => return Id::parse(v).map_err(E::custom),
+            UUID => decode_uuid(v),
+            HEX => decode_hex(v),
+            ANY => match Id::parse(v) {
+                Ok(id) => return Ok(id),
+                Err(error) => match decode_uuid(v).or_else(|| decode_hex(v)) {
+                    Some(bytes) => Some(bytes),
+                    None => return Err(E::custom(error)),
+                },
+            },
+            _ => return Err(E::invalid_type(Unexpected::Str(v), &self)),
+        };
+
+        bytes
+            .ok_or_else(|| E::invalid_value(Unexpected::Str(v), &self))
+            .and_then(|bytes| Id::from_bytes(bytes).map_err(E::custom))
+    }
+
+    fn visit_bytes<E: Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+        match FORM {
+            BYTES | ANY => Id::try_from(v).map_err(E::custom),
+            _ => Err(E::invalid_type(Unexpected::Bytes(v), &self)),
+        }
+    }
+
+    fn visit_u128<E: Error>(self, v: u128) -> Result<Self::Value, E> {
+        match FORM {
+            U128 | ANY => Id::try_from(v).map_err(E::custom),
+            _ => Err(E::invalid_type(Unexpected::Other("u128"), &self)),
+        }
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        if !matches!(FORM, BYTES | ANY) {
+            return Err(A::Error::invalid_type(Unexpected::Seq, &self));
+        }
+
+        let mut bytes = [0; 16];
+
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            *byte = seq
+                .next_element()?
+                .ok_or_else(|| A::Error::invalid_length(i, &self))?;
+        }
+
+        if seq.next_element::<IgnoredAny>()?.is_some() {
+            return Err(A::Error::invalid_length(17, &self));
+        }
+
+        Id::from_bytes(bytes).map_err(A::Error::custom)
+    }
+}
+
+fn serialize<const FORM: u8, T, S>(id: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: Identifiable,
+    S: Serializer,
+{
+    As::<FORM, Id>(id.id()).serialize(serializer)
+}
+
+fn deserialize<'de, const FORM: u8, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    T: TryFrom<Id>,
+    T::Error: Display,
+    D: Deserializer<'de>,
+{
+    As::<FORM, T>::deserialize(deserializer).map(|As(id)| id)
+}
+
+fn serialize_option<const FORM: u8, T, S>(id: &Option<T>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: Identifiable,
+    S: Serializer,
+{
+    id.as_ref()
+        .map(|id| As::<FORM, Id>(id.id()))
+        .serialize(serializer)
+}
+
+fn deserialize_option<'de, const FORM: u8, T, D>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    T: TryFrom<Id>,
+    T::Error: Display,
+    D: Deserializer<'de>,
+{
+    Option::<As<FORM, T>>::deserialize(deserializer).map(|id| id.map(|As(id)| id))
+}
+
+fn serialize_vec<const FORM: u8, T, S>(ids: &[T], serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: Identifiable,
+    S: Serializer,
+{
+    serializer.collect_seq(ids.iter().map(|id| As::<FORM, Id>(id.id())))
+}
+
+fn deserialize_vec<'de, const FORM: u8, T, D>(
+    deserializer: D,
+) -> Result<alloc::vec::Vec<T>, D::Error>
+where
+    T: TryFrom<Id>,
+    T::Error: Display,
+    D: Deserializer<'de>,
+{
+    alloc::vec::Vec::<As<FORM, T>>::deserialize(deserializer)
+        .map(|ids| ids.into_iter().map(|As(id)| id).collect())
+}
+
+const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+/// View an ASCII buffer as a string.
+fn ascii(bytes: &[u8]) -> &str {
+    core::str::from_utf8(bytes).unwrap_or_default()
+}
+
+fn encode_hex(id: Id) -> [u8; 32] {
+    let mut output = [0; 32];
+
+    for (i, byte) in id.to_bytes().into_iter().enumerate() {
+        output[i * 2] = HEX_DIGITS[(byte >> 4) as usize];
+        output[i * 2 + 1] = HEX_DIGITS[(byte & 0xf) as usize];
+    }
+
+    output
+}
+
+fn encode_uuid(id: Id) -> [u8; 36] {
+    let hex = encode_hex(id);
+    let mut output = [b'-'; 36];
+
+    output[..8].copy_from_slice(&hex[..8]);
+    output[9..13].copy_from_slice(&hex[8..12]);
+    output[14..18].copy_from_slice(&hex[12..16]);
+    output[19..23].copy_from_slice(&hex[16..20]);
+    output[24..].copy_from_slice(&hex[20..]);
+
+    output
+}
+
+/// Decode 32 hexadecimal digits in either case.
+fn decode_hex(value: &str) -> Option<[u8; 16]> {
+    let value = value.as_bytes();
+
+    if value.len() != 32 {
+        return None;
+    }
+
+    let mut output = [0; 16];
+
+    for (i, byte) in output.iter_mut().enumerate() {
+        let high = (value[i * 2] as char).to_digit(16)?;
+        let low = (value[i * 2 + 1] as char).to_digit(16)?;
+        *byte = (high << 4 | low) as u8;
+    }
+
+    Some(output)
+}
+
+/// Decode a hyphenated or simple UUID string.
+fn decode_uuid(value: &str) -> Option<[u8; 16]> {
+    if value.len() != 36 {
+        return decode_hex(value);
+    }
+
+    let mut hex = [0; 32];
+    let mut length = 0;
+
+    for (i, c) in value.bytes().enumerate() {
+        match (i, c) {
+            (8 | 13 | 18 | 23, b'-') => {}
+            (8 | 13 | 18 | 23, _) | (_, b'-') => return None,
+            _ => {
+                hex[length] = c;
+                length += 1;
+            }
+        }
+    }
+
+    decode_hex(ascii(&hex))
+}
+
+#[cfg(feature = "bson")]
+mod bson {
+    use crate::id::Id;
+    use crate::identifiable::Identifiable;
+    use bson::Bson;
+    use bson::spec::BinarySubtype;
+    use core::fmt::Display;
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<T, S>(id: &T, subtype: BinarySubtype, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Identifiable,
+        S: Serializer,
+    {
+        id.id().to_bson_binary(subtype).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: TryFrom<Id>,
+        T::Error: Display,
+        D: Deserializer<'de>,
+    {
+        let id = Id::try_from(Bson::deserialize(deserializer)?).map_err(Error::custom)?;
+        T::try_from(id).map_err(Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{id::Id, prefix::Prefix, serde, tagged::Tagged, typed::TypedId};
+    use serde_json::{Value, json, value::Serializer};
+
+    struct User;
+
+    impl Tagged for User {
+        const PREFIX: Prefix = Prefix::from_str_const("user");
+    }
+
+    const ID: &str = "user_02v58c5a3fy30k560qrtg4";
+    const UUID: &str = "accb202d-950c-2a86-ff0c-1329817c6a04";
+    const HEX: &str = "accb202d950c2a86ff0c1329817c6a04";
+
+    #[test]
+    fn representations() {
+        let id = Id::parse(ID).unwrap();
+        let bytes = json!(id.to_bytes());
+
+        assert_eq!(
+            json!(ID),
+            serde::as_string::serialize(&id, Serializer).unwrap()
+        );
+        assert_eq!(bytes, serde::as_bytes::serialize(&id, Serializer).unwrap());
+        assert_eq!(
+            json!(UUID),
+            serde::as_uuid::serialize(&id, Serializer).unwrap()
+        );
+        assert_eq!(
+            json!(HEX),
+            serde::as_hex::serialize(&id, Serializer).unwrap()
+        );
+
+        assert_eq!(
+            id,
+            serde::as_string::deserialize::<Id, _>(json!(ID)).unwrap()
+        );
+        assert_eq!(id, serde::as_bytes::deserialize::<Id, _>(bytes).unwrap());
+        assert_eq!(
+            id,
+            serde::as_uuid::deserialize::<Id, _>(json!(UUID)).unwrap()
+        );
+        assert_eq!(
+            id,
+            serde::as_uuid::deserialize::<Id, _>(json!(HEX)).unwrap()
+        );
+        assert_eq!(
+            id,
+            serde::as_hex::deserialize::<Id, _>(json!(HEX.to_uppercase())).unwrap()
+        );
+
+        assert!(serde::as_string::deserialize::<Id, _>(json!(UUID)).is_err());
+        assert!(serde::as_uuid::deserialize::<Id, _>(json!(ID)).is_err());
+        assert!(serde::as_hex::deserialize::<Id, _>(json!(UUID)).is_err());
+    }
+
+    #[test]
+    fn u128() {
+        let id = Id::parse(ID).unwrap();
+        let integer = "229681904185790704546245125585216760324";
+
+        let mut output = Vec::new();
+        serde::as_u128::serialize(&id, &mut serde_json::Serializer::new(&mut output)).unwrap();
+        assert_eq!(integer.as_bytes(), output);
+
+        let mut deserializer = serde_json::Deserializer::from_str(integer);
+        assert_eq!(
+            id,
+            serde::as_u128::deserialize::<Id, _>(&mut deserializer).unwrap()
+        );
+    }
+
+    #[test]
+    fn any() {
+        let id = Id::parse(ID).unwrap();
+
+        for value in [json!(ID), json!(UUID), json!(HEX), json!(id.to_bytes())] {
+            assert_eq!(id, serde::as_any::deserialize::<Id, _>(value).unwrap());
+        }
+
+        assert_eq!(
+            json!(ID),
+            serde::as_any::serialize(&id, Serializer).unwrap()
+        );
+        assert!(serde::as_any::deserialize::<Id, _>(json!("nope")).is_err());
+    }
+
+    #[test]
+    fn option_vec() {
+        let id = TypedId::<User>::parse(ID).unwrap();
+
+        assert_eq!(
+            json!(UUID),
+            serde::as_uuid::option::serialize(&Some(id), Serializer).unwrap()
+        );
+        assert_eq!(
+            Value::Null,
+            serde::as_uuid::option::serialize::<Id, _>(&None, Serializer).unwrap()
+        );
+        assert_eq!(
+            Some(id),
+            serde::as_uuid::option::deserialize(json!(UUID)).unwrap()
+        );
+        assert_eq!(
+            None::<Id>,
+            serde::as_uuid::option::deserialize(Value::Null).unwrap()
+        );
+
+        assert_eq!(
+            json!([HEX, HEX]),
+            serde::as_hex::vec::serialize(&[id, id], Serializer).unwrap()
+        );
+        assert_eq!(
+            vec![id, id],
+            serde::as_any::vec::deserialize::<TypedId<User>, _>(json!([ID, UUID])).unwrap()
+        );
+        assert!(
+            serde::as_any::vec::deserialize::<TypedId<User>, _>(json!([
+                "org_02v58c5a3fy30k560qrtg4"
+            ]))
+            .is_err()
+        );
+    }
+}
@@ -0,0 +1,132 @@
+use crate::encoding::encode_id_into;
+use crate::id::Id;
+use core::borrow::Borrow;
+use core::cmp::Ordering;
+use core::fmt::{Debug, Display, Formatter};
+use core::hash::{Hash, Hasher};
+use core::ops::Deref;
+
+/// The string representation of an [`Id`], stored inline without any heap
+/// allocation.
+///
+/// ```
+/// # use souvenir_core::{id::Id, string::IdString};
+/// let id = Id::parse("user_02v58c5a3fy30k560qrtg4").unwrap();
+/// let string: IdString = id.encode();
+///
+/// assert_eq!(&*string, "user_02v58c5a3fy30k560qrtg4");
+/// assert_eq!(string.len(), 27);
+/// ```
+#[derive(Copy, Clone)]
+pub struct IdString {
+    buf: [u8; IdString::MAX_LENGTH],
+    len: u8,
+}
+
+impl IdString {
+    /// The maximum length of the string representation of an [`Id`].
+    pub const MAX_LENGTH: usize = 27;
+
+    /// Encode the provided [`Id`].
+    pub fn new(id: Id) -> Self {
+        let mut buf = [0; Self::MAX_LENGTH];
+        let len = encode_id_into(id, &mut buf) as u8;
+
+        Self { buf, len }
+    }
+
+    /// Get the string slice.
+    pub fn as_str(&self) -> &str {
+        // UNSAFE: All bytes are guaranteed to be in ASCII range.
+        unsafe { core::str::from_utf8_unchecked(&self.buf[..self.len as usize]) }
+    }
+}
+
+impl Deref for IdString {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl AsRef<str> for IdString {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl AsRef<[u8]> for IdString {
+    fn as_ref(&self) -> &[u8] {
+        self.as_str().as_bytes()
+    }
+}
+
+impl Borrow<str> for IdString {
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl PartialEq for IdString {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for IdString {}
+
+impl PartialEq<str> for IdString {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for IdString {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl PartialOrd for IdString {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for IdString {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+impl Hash for IdString {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state)
+    }
+}
+
+impl Debug for IdString {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl Display for IdString {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<Id> for IdString {
+    fn from(value: Id) -> Self {
+        Self::new(value)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<IdString> for alloc::string::String {
+    fn from(value: IdString) -> Self {
+        value.as_str().into()
+    }
+}
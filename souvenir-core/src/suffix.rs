@@ -1,25 +1,54 @@
-use std::{
+use core::{
     fmt::{Debug, Display},
     str::FromStr,
 };
 
 use crate::{
-    encoding::{decode_suffix, encode_suffix},
+    encoding::{decode_suffix, decode_suffix_with, encode_suffix_into},
     error::{Error, Result},
+    options::ParseOptions,
 };
 
 /// A valid [`Id`](crate::id::Id) suffix.
+///
+/// Time-ordered suffixes store a 48-bit millisecond Unix timestamp in their
+/// highest bits, followed by 60 random bits. Since the string encoding is
+/// fixed-width and big-endian, these suffixes sort the same way as strings
+/// as they do numerically.
 #[derive(Copy, Clone, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub struct Suffix(u128);
 
 impl Suffix {
     const MASK: u128 = (1 << 108) - 1;
 
+    /// The number of bits used by the timestamp of a time-ordered [`Suffix`].
+    pub const TIMESTAMP_BITS: u32 = 48;
+
+    /// The number of random bits in a time-ordered [`Suffix`].
+    pub const RANDOM_BITS: u32 = 108 - Self::TIMESTAMP_BITS;
+
     /// Create a [`Suffix`] from its inner [`u128`] value.
     pub const fn new(value: u128) -> Self {
         Self(value & Self::MASK)
     }
 
+    /// Create a time-ordered [`Suffix`] from a millisecond Unix timestamp and
+    /// a random value. Only the lowest 48 bits of the timestamp and the lowest
+    /// 60 bits of the random value are used.
+    pub const fn from_timestamp(timestamp: u64, random: u64) -> Self {
+        let timestamp = (timestamp as u128) & ((1 << Self::TIMESTAMP_BITS) - 1);
+        let random = (random as u128) & ((1 << Self::RANDOM_BITS) - 1);
+
+        Self((timestamp << Self::RANDOM_BITS) | random)
+    }
+
+    /// Retrieve the millisecond Unix timestamp stored in this [`Suffix`].
+    ///
+    /// This is only meaningful for time-ordered suffixes.
+    pub const fn timestamp(self) -> u64 {
+        (self.0 >> Self::RANDOM_BITS) as u64
+    }
+
     /// Retrieve the inner [`u128`] value from this [`Suffix`].
     pub const fn to_u128(self) -> u128 {
         self.0
@@ -29,24 +58,34 @@ impl Suffix {
     pub fn parse(suffix: &str) -> Result<Self> {
         decode_suffix(suffix)
     }
+
+    /// Attempt to parse the provided string into a [`Suffix`] using the
+    /// provided [`ParseOptions`].
+    pub fn parse_with(suffix: &str, options: ParseOptions) -> Result<Self> {
+        decode_suffix_with(suffix, options)
+    }
 }
 
 impl Debug for Suffix {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "{self}")
     }
 }
 
 impl Display for Suffix {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", encode_suffix(*self))
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut buf = [0; 22];
+        encode_suffix_into(*self, &mut buf);
+
+        // UNSAFE: All bytes are guaranteed to be in ASCII range.
+        f.pad(unsafe { core::str::from_utf8_unchecked(&buf) })
     }
 }
 
 impl FromStr for Suffix {
     type Err = Error;
 
-    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+    fn from_str(s: &str) -> core::result::Result<Self, Self::Err> {
         Self::parse(s)
     }
 }
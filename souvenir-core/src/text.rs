@@ -0,0 +1,14 @@
+use crate::id::Id;
+
+/// A wrapper which stores an identifier with SeaORM as `TEXT` in its
+/// prefixed string form, instead of the default `uuid`.
+///
+/// `T` can be an [`Id`], a [`TypedId`](crate::typed::TypedId) or a
+/// [`KnownId`](crate::registry::KnownId).
+///
+/// SeaORM values do not know which backend they will be sent to, so use this
+/// wrapper for SQLite columns shared with the `sqlx`, `diesel` or `rusqlite`
+/// integrations, which store identifiers as `TEXT`. Both this wrapper and the
+/// identifier types themselves decode from either representation.
+#[derive(Copy, Clone, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct TextId<T = Id>(pub T);
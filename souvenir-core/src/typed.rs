@@ -0,0 +1,370 @@
+use crate::error::{Error, Result};
+use crate::id::{Id, IdBytes};
+use crate::identifiable::Identifiable;
+use crate::options::ParseOptions;
+use crate::prefix::Prefix;
+use crate::string::IdString;
+use crate::suffix::Suffix;
+use crate::tagged::Tagged;
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+use core::cmp::Ordering;
+use core::fmt::{Debug, Display, Formatter};
+use core::hash::{Hash, Hasher};
+use core::marker::PhantomData;
+use core::str::FromStr;
+
+/// An [`Id`] whose prefix is fixed at compile time by the [`Tagged`] type `T`.
+///
+/// A [`TypedId`] has the exact same layout as an [`Id`], but its prefix is
+/// always checked against [`T::PREFIX`](Tagged::PREFIX) whenever it is
+/// parsed, deserialized or decoded from a database.
+///
+/// ```
+/// # use souvenir_core::{id::Id, prefix::Prefix, tagged::Tagged, typed::TypedId};
+/// struct User;
+///
+/// impl Tagged for User {
+///     const PREFIX: Prefix = unsafe { Prefix::new_unchecked(0b10101_10011_00101_10010) };
+/// }
+///
+/// let id: TypedId<User> = TypedId::parse("user_02v58c5a3fy30k560qrtg4").unwrap();
+/// assert_eq!(id.to_string(), "user_02v58c5a3fy30k560qrtg4");
+///
+/// assert!(TypedId::<User>::parse("org_02v58c5a3fy30k560qrtg4").is_err());
+///
+/// let erased: Id = id.into();
+/// assert_eq!(TypedId::<User>::try_from(erased), Ok(id));
+/// ```
+#[cfg_attr(
+    feature = "diesel",
+    derive(::diesel::AsExpression, ::diesel::FromSqlRow)
+)]
+#[cfg_attr(all(feature = "diesel", feature = "postgres"), diesel(sql_type = ::diesel::sql_types::Uuid))]
+#[cfg_attr(all(feature = "diesel", any(feature = "mysql", feature = "sqlite")), diesel(sql_type = ::diesel::sql_types::Binary))]
+#[cfg_attr(all(feature = "diesel", feature = "sqlite"), diesel(sql_type = ::diesel::sql_types::Text))]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        ::zerocopy::Immutable,
+        ::zerocopy::IntoBytes,
+        ::zerocopy::KnownLayout,
+        ::zerocopy::Unaligned
+    )
+)]
+#[repr(transparent)]
+pub struct TypedId<T: Tagged> {
+    id: Id,
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<T: Tagged> TypedId<T> {
+    /// Create a new [`TypedId`] with the provided suffix.
+    pub const fn new(suffix: Suffix) -> Self {
+        unsafe { Self::from_id_unchecked(Id::new(T::PREFIX, suffix)) }
+    }
+
+    /// Create a [`TypedId`] from an [`Id`].
+    /// If the prefix of the provided [`Id`] does not match `T`, this will
+    /// error.
+    pub fn from_id(id: Id) -> Result<Self> {
+        let found = id.prefix();
+
+        if found != T::PREFIX {
+            return Err(Error::PrefixMismatch {
+                expected: T::PREFIX,
+                found,
+            });
+        }
+
+        Ok(unsafe { Self::from_id_unchecked(id) })
+    }
+
+    /// Create a [`TypedId`] from an [`Id`].
+    /// The prefix of the provided [`Id`] is not checked.
+    ///
+    /// # Safety
+    /// This method is unsafe because the API assumes that the prefix always
+    /// matches `T`.
+    pub const unsafe fn from_id_unchecked(id: Id) -> Self {
+        Self {
+            id,
+            marker: PhantomData,
+        }
+    }
+
+    /// Create a [`TypedId`] from an [`Id`] with any prefix, keeping its
+    /// suffix.
+    pub const fn cast(id: Id) -> Self {
+        Self::new(id.suffix())
+    }
+
+    /// Create a new [`TypedId`] with the following bytes. If the provided
+    /// bytes do not form a valid [`Id`] with the correct prefix, this method
+    /// will error.
+    pub fn from_bytes(value: IdBytes) -> Result<Self> {
+        Id::from_bytes(value).and_then(Self::from_id)
+    }
+
+    /// Get a reference to the untyped [`Id`].
+    pub fn as_id(&self) -> &Id {
+        &self.id
+    }
+
+    /// Get the untyped [`Id`].
+    pub const fn to_id(self) -> Id {
+        self.id
+    }
+
+    /// Get the data value of the identifier.
+    pub fn as_bytes(&self) -> &IdBytes {
+        self.id.as_bytes()
+    }
+
+    /// Get the data value of the identifier.
+    pub const fn to_bytes(self) -> IdBytes {
+        self.id.to_bytes()
+    }
+
+    /// Get the data value of the identifier as a [`u128`].
+    pub const fn to_u128(self) -> u128 {
+        self.id.to_u128()
+    }
+
+    /// Get the data value of the identifier as an [`i128`].
+    pub const fn to_i128(self) -> i128 {
+        self.id.to_i128()
+    }
+
+    /// Get the prefix of this identifier, which is always `T::PREFIX`.
+    pub const fn prefix(self) -> Prefix {
+        T::PREFIX
+    }
+
+    /// Get the suffix of this identifier.
+    pub const fn suffix(self) -> Suffix {
+        self.id.suffix()
+    }
+
+    /// Get the millisecond Unix timestamp stored in the suffix of this
+    /// identifier.
+    ///
+    /// This is only meaningful for time-ordered identifiers.
+    pub const fn timestamp(self) -> u64 {
+        self.id.timestamp()
+    }
+
+    /// Test to see if the provided string is a valid [`TypedId`].
+    pub fn test(value: &str) -> bool {
+        Self::parse(value).is_ok()
+    }
+
+    /// Attempt to parse the provided string into a [`TypedId`].
+    pub fn parse(value: &str) -> Result<Self> {
+        Id::parse(value).and_then(Self::from_id)
+    }
+
+    /// Attempt to parse the provided string into a [`TypedId`] using the
+    /// provided [`ParseOptions`].
+    pub fn parse_with(value: &str, options: ParseOptions) -> Result<Self> {
+        Id::parse_with(value, options).and_then(Self::from_id)
+    }
+
+    /// Attempt to parse the provided string into a [`TypedId`], accepting
+    /// uppercase characters and normalizing ambiguous characters.
+    /// See [`ParseOptions::lenient`].
+    pub fn parse_lenient(value: &str) -> Result<Self> {
+        Self::parse_with(value, ParseOptions::lenient())
+    }
+
+    /// Get the string representation of this [`TypedId`] without
+    /// allocating.
+    pub fn encode(self) -> IdString {
+        self.id.encode()
+    }
+
+    /// Get the string representation of this [`TypedId`], followed by a
+    /// check symbol. See [`Id::to_checked_string`].
+    #[cfg(feature = "alloc")]
+    pub fn to_checked_string(self) -> String {
+        self.id.to_checked_string()
+    }
+
+    /// Attempt to parse the provided string, which must end with a check
+    /// symbol, into a [`TypedId`]. See [`Id::parse_checked`].
+    pub fn parse_checked(value: &str) -> Result<Self> {
+        Id::parse_checked(value).and_then(Self::from_id)
+    }
+}
+
+impl<T: Tagged> Copy for TypedId<T> {}
+
+impl<T: Tagged> Clone for TypedId<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: Tagged> PartialEq for TypedId<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl<T: Tagged> Eq for TypedId<T> {}
+
+impl<T: Tagged> PartialOrd for TypedId<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Tagged> Ord for TypedId<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.id.cmp(&other.id)
+    }
+}
+
+impl<T: Tagged> Hash for TypedId<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.id.hash(state)
+    }
+}
+
+impl<T: Tagged> Debug for TypedId<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{self}")
+    }
+}
+
+impl<T: Tagged> Display for TypedId<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(&self.id, f)
+    }
+}
+
+impl<T: Tagged> Default for TypedId<T> {
+    fn default() -> Self {
+        Self::new(Suffix::default())
+    }
+}
+
+impl<T: Tagged> FromStr for TypedId<T> {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::parse(s)
+    }
+}
+
+impl<T: Tagged> Identifiable for TypedId<T> {
+    fn id(&self) -> Id {
+        self.id
+    }
+}
+
+impl<T: Tagged> From<TypedId<T>> for Id {
+    fn from(value: TypedId<T>) -> Self {
+        value.id
+    }
+}
+
+impl<T: Tagged> From<TypedId<T>> for u128 {
+    fn from(value: TypedId<T>) -> Self {
+        value.to_u128()
+    }
+}
+
+impl<T: Tagged> From<TypedId<T>> for i128 {
+    fn from(value: TypedId<T>) -> Self {
+        value.to_i128()
+    }
+}
+
+impl<T: Tagged> From<TypedId<T>> for IdBytes {
+    fn from(value: TypedId<T>) -> Self {
+        value.to_bytes()
+    }
+}
+
+impl<T: Tagged> TryFrom<Id> for TypedId<T> {
+    type Error = Error;
+
+    fn try_from(value: Id) -> Result<Self> {
+        Self::from_id(value)
+    }
+}
+
+impl<T: Tagged> TryFrom<u128> for TypedId<T> {
+    type Error = Error;
+
+    fn try_from(value: u128) -> Result<Self> {
+        Self::from_bytes(value.to_be_bytes())
+    }
+}
+
+impl<T: Tagged> TryFrom<i128> for TypedId<T> {
+    type Error = Error;
+
+    fn try_from(value: i128) -> Result<Self> {
+        Self::from_bytes(value.to_be_bytes())
+    }
+}
+
+impl<T: Tagged> TryFrom<IdBytes> for TypedId<T> {
+    type Error = Error;
+
+    fn try_from(value: IdBytes) -> Result<Self> {
+        Self::from_bytes(value)
+    }
+}
+
+impl<T: Tagged> TryFrom<&[u8]> for TypedId<T> {
+    type Error = Error;
+
+    fn try_from(value: &[u8]) -> Result<Self> {
+        Id::try_from(value).and_then(Self::from_id)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{error::Error, id::Id, prefix::Prefix, tagged::Tagged, typed::TypedId};
+
+    struct User;
+
+    impl Tagged for User {
+        const PREFIX: Prefix = unsafe { Prefix::new_unchecked(0b10101_10011_00101_10010) };
+    }
+
+    #[test]
+    fn parse_matching() {
+        let id = TypedId::<User>::parse("user_02v58c5a3fy30k560qrtg4").unwrap();
+
+        assert_eq!(id.prefix(), User::PREFIX);
+        assert_eq!(id.to_string(), "user_02v58c5a3fy30k560qrtg4");
+    }
+
+    #[test]
+    fn parse_mismatch() {
+        assert_eq!(
+            Err(Error::PrefixMismatch {
+                expected: User::PREFIX,
+                found: "org".parse().unwrap(),
+            }),
+            TypedId::<User>::parse("org_02v58c5a3fy30k560qrtg4")
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        let id = Id::parse("user_02v58c5a3fy30k560qrtg4").unwrap();
+        let typed = TypedId::<User>::try_from(id).unwrap();
+
+        assert_eq!(id, Id::from(typed));
+        assert_eq!(
+            typed,
+            TypedId::cast(Id::new("org".parse().unwrap(), id.suffix()))
+        );
+    }
+}
@@ -0,0 +1,44 @@
+use proc_macro::TokenStream;
+use quote::quote;
+use souvenir_core::prefix::Prefix;
+use syn::{LitStr, Path, parse::Parse, parse_macro_input};
+
+enum GeneratorInput {
+    Literal(LitStr),
+    Tagged(Path),
+}
+
+impl Parse for GeneratorInput {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        Ok(if input.peek(LitStr) {
+            Self::Literal(input.parse()?)
+        } else {
+            Self::Tagged(input.parse()?)
+        })
+    }
+}
+
+pub fn generator(input: TokenStream) -> TokenStream {
+    match parse_macro_input!(input as GeneratorInput) {
+        GeneratorInput::Literal(literal) => {
+            let value = literal.value();
+
+            if let Ok(prefix) = Prefix::parse(&value) {
+                let raw = prefix.to_u32();
+
+                return quote! {
+                    ::souvenir::IdGenerator::new(unsafe {
+                        ::souvenir::Prefix::new_unchecked(#raw)
+                    })
+                }
+                .into();
+            }
+
+            panic!("\"{}\" is not a valid prefix!", value);
+        }
+        GeneratorInput::Tagged(path) => quote! {
+            ::souvenir::IdGenerator::tagged::<#path>()
+        }
+        .into(),
+    }
+}
@@ -8,6 +8,7 @@
 
 extern crate proc_macro;
 
+mod generator;
 mod id;
 mod identifiable;
 mod prefix;
@@ -59,6 +60,29 @@ pub fn prefix(input: TokenStream) -> TokenStream {
     prefix::prefix(input)
 }
 
+/// Create an `IdGenerator` based on some literal input.
+/// All inputs are verified at compile time to ensure that the prefix is
+/// valid, so the result can be used to initialize a `static`.
+///
+/// ```
+/// # use souvenir::{generator, Id, IdGenerator, Tagged};
+/// static USERS: IdGenerator = generator!("user");
+///
+/// let id: Id = USERS.generate().unwrap();
+/// assert_eq!(id.prefix().to_string(), "user");
+///
+/// #[derive(Tagged)]
+/// #[souvenir(tag = "org")]
+/// struct Org;
+///
+/// static ORGS: IdGenerator = generator!(Org);
+/// assert!(ORGS.generate().unwrap() < ORGS.generate().unwrap());
+/// ```
+#[proc_macro]
+pub fn generator(input: TokenStream) -> TokenStream {
+    generator::generator(input)
+}
+
 /// Automatically implement `Identifiable`.
 ///
 /// ```
@@ -7,14 +7,15 @@ pub fn prefix(input: TokenStream) -> TokenStream {
     let literal = parse_macro_input!(input as LitStr);
     let value = literal.value();
 
-    if let Ok(prefix) = Prefix::parse(&value) {
-        let raw = prefix.to_u32();
+    match Prefix::parse(&value) {
+        Ok(prefix) => {
+            let raw = prefix.to_u32();
 
-        return quote! {
-            unsafe { ::souvenir::Prefix::new_unchecked(#raw) }
+            quote! {
+                unsafe { ::souvenir::Prefix::new_unchecked(#raw) }
+            }
+            .into()
         }
-        .into();
+        Err(error) => panic!("\"{}\" is not a valid prefix: {}", value, error),
     }
-
-    panic!("\"{}\" is not a valid prefix!", value);
 }
@@ -0,0 +1,403 @@
+//! A Postgres extension providing a native `souvenir` type.
+//!
+//! Unlike the `uuid` columns used by the `sqlx` and `diesel` integrations,
+//! values of this type are displayed in their prefixed string form and are
+//! validated on input. Like `uuid`, values are stored as a fixed-length
+//! 16-byte datum, and the binary protocol sends the same 16 bytes, so values
+//! can be cast to and from `uuid` to migrate existing columns:
+//!
+//! ```sql
+//! CREATE EXTENSION souvenir_pg;
+//!
+//! ALTER TABLE users ALTER COLUMN id TYPE souvenir USING id::souvenir;
+//! SELECT id, souvenir_prefix(id), id::uuid FROM users;
+//! ```
+
+use pgrx::callconv::{Arg, ArgAbi, BoxRet, FcInfo};
+use pgrx::datum::{Datum, Internal, Uuid};
+use pgrx::pgrx_sql_entity_graph::metadata::{
+    ArgumentError, Returns, ReturnsError, SqlMapping, SqlTranslatable,
+};
+use pgrx::prelude::*;
+use pgrx::{PgMemoryContexts, rust_regtypein};
+use souvenir_core::encoding::{decode_id, encode_id};
+use souvenir_core::{error::Error, id::Id, id::IdBytes};
+use std::ffi::{CStr, CString};
+
+::pgrx::pg_module_magic!();
+
+/// A prefixed identifier.
+///
+/// The 16 bytes are validated when a value is created, so they are compared
+/// and hashed directly, without decoding the identifier.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct Souvenir(IdBytes);
+
+impl Souvenir {
+    /// Get the identifier stored in this value.
+    fn to_id(self) -> Id {
+        Id::from_bytes(self.0)
+            .unwrap_or_else(|error| raise(PgSqlErrorCode::ERRCODE_DATA_CORRUPTED, error))
+    }
+}
+
+impl From<Id> for Souvenir {
+    fn from(value: Id) -> Self {
+        Self(value.to_bytes())
+    }
+}
+
+impl FromDatum for Souvenir {
+    unsafe fn from_polymorphic_datum(
+        datum: pg_sys::Datum,
+        is_null: bool,
+        _: pg_sys::Oid,
+    ) -> Option<Self> {
+        if is_null {
+            None
+        } else {
+            // SAFETY: a `souvenir` datum is a pointer to 16 bytes
+            Some(Self(unsafe {
+                datum.cast_mut_ptr::<IdBytes>().read_unaligned()
+            }))
+        }
+    }
+}
+
+impl IntoDatum for Souvenir {
+    fn into_datum(self) -> Option<pg_sys::Datum> {
+        // SAFETY: CurrentMemoryContext is always valid
+        let ptr = unsafe { PgMemoryContexts::CurrentMemoryContext.palloc_slice::<u8>(16) };
+        ptr.copy_from_slice(&self.0);
+
+        Some(ptr.as_ptr().into())
+    }
+
+    fn type_oid() -> pg_sys::Oid {
+        rust_regtypein::<Self>()
+    }
+}
+
+unsafe impl<'fcx> ArgAbi<'fcx> for Souvenir {
+    unsafe fn unbox_arg_unchecked(arg: Arg<'_, 'fcx>) -> Self {
+        let index = arg.index();
+
+        unsafe { arg.unbox_arg_using_from_datum() }
+            .unwrap_or_else(|| panic!("argument {index} must not be null"))
+    }
+}
+
+unsafe impl BoxRet for Souvenir {
+    unsafe fn box_into<'fcx>(self, fcinfo: &mut FcInfo<'fcx>) -> Datum<'fcx> {
+        match self.into_datum() {
+            Some(datum) => unsafe { fcinfo.return_raw_datum(datum) },
+            None => fcinfo.return_null(),
+        }
+    }
+}
+
+unsafe impl SqlTranslatable for Souvenir {
+    fn argument_sql() -> Result<SqlMapping, ArgumentError> {
+        Ok(SqlMapping::literal("souvenir"))
+    }
+
+    fn return_sql() -> Result<Returns, ReturnsError> {
+        Ok(Returns::One(SqlMapping::literal("souvenir")))
+    }
+}
+
+extension_sql!(
+    "CREATE TYPE souvenir;",
+    name = "souvenir_shell",
+    creates = [Type(Souvenir)]
+);
+
+/// Parse an identifier from its string form.
+#[pg_extern(immutable, strict, parallel_safe)]
+fn souvenir_in(input: &CStr) -> Souvenir {
+    input
+        .to_str()
+        .map_err(|_| Error::InvalidData)
+        .and_then(decode_id)
+        .map(Souvenir::from)
+        .unwrap_or_else(|error| raise(PgSqlErrorCode::ERRCODE_INVALID_TEXT_REPRESENTATION, error))
+}
+
+/// Format an identifier as its string form.
+#[pg_extern(immutable, strict, parallel_safe)]
+fn souvenir_out(id: Souvenir) -> CString {
+    CString::new(encode_id(id.to_id())).unwrap()
+}
+
+/// Read an identifier from the 16 bytes of the binary protocol.
+#[pg_extern(immutable, strict, parallel_safe)]
+fn souvenir_recv(mut internal: Internal) -> Souvenir {
+    // SAFETY: Postgres passes the message buffer to receive functions
+    let buffer = unsafe { internal.get_mut::<pg_sys::StringInfoData>() }
+        .expect("receive buffer must not be null");
+
+    // SAFETY: the unread part of the buffer is between `cursor` and `len`
+    let bytes = unsafe {
+        std::slice::from_raw_parts(
+            buffer.data.add(buffer.cursor as usize) as *const u8,
+            (buffer.len - buffer.cursor) as usize,
+        )
+    };
+
+    let id = Id::try_from(bytes).unwrap_or_else(|error| {
+        raise(PgSqlErrorCode::ERRCODE_INVALID_BINARY_REPRESENTATION, error)
+    });
+    buffer.cursor = buffer.len;
+
+    Souvenir::from(id)
+}
+
+/// Write an identifier as the 16 bytes of the binary protocol.
+#[pg_extern(immutable, strict, parallel_safe)]
+fn souvenir_send(id: Souvenir) -> Vec<u8> {
+    id.0.to_vec()
+}
+
+extension_sql!(
+    r#"
+CREATE TYPE souvenir (
+    INTERNALLENGTH = 16,
+    INPUT = souvenir_in,
+    OUTPUT = souvenir_out,
+    RECEIVE = souvenir_recv,
+    SEND = souvenir_send,
+    ALIGNMENT = char,
+    STORAGE = plain
+);
+"#,
+    name = "souvenir_type",
+    requires = [
+        "souvenir_shell",
+        souvenir_in,
+        souvenir_out,
+        souvenir_recv,
+        souvenir_send
+    ]
+);
+
+/// Raise a Postgres error for an invalid identifier.
+fn raise(code: PgSqlErrorCode, error: Error) -> ! {
+    ereport!(
+        ERROR,
+        code,
+        format!("invalid input syntax for type souvenir: {error}")
+    );
+}
+
+#[pg_operator(immutable, parallel_safe, requires = ["souvenir_type"])]
+#[opname(=)]
+#[commutator(=)]
+#[negator(<>)]
+#[restrict(eqsel)]
+#[join(eqjoinsel)]
+#[merges]
+#[hashes]
+fn souvenir_eq(left: Souvenir, right: Souvenir) -> bool {
+    left == right
+}
+
+#[pg_operator(immutable, parallel_safe, requires = ["souvenir_type"])]
+#[opname(<>)]
+#[commutator(<>)]
+#[negator(=)]
+#[restrict(neqsel)]
+#[join(neqjoinsel)]
+fn souvenir_ne(left: Souvenir, right: Souvenir) -> bool {
+    left != right
+}
+
+#[pg_operator(immutable, parallel_safe, requires = ["souvenir_type"])]
+#[opname(<)]
+#[commutator(>)]
+#[negator(>=)]
+#[restrict(scalarltsel)]
+#[join(scalarltjoinsel)]
+fn souvenir_lt(left: Souvenir, right: Souvenir) -> bool {
+    left < right
+}
+
+#[pg_operator(immutable, parallel_safe, requires = ["souvenir_type"])]
+#[opname(<=)]
+#[commutator(>=)]
+#[negator(>)]
+#[restrict(scalarlesel)]
+#[join(scalarlejoinsel)]
+fn souvenir_le(left: Souvenir, right: Souvenir) -> bool {
+    left <= right
+}
+
+#[pg_operator(immutable, parallel_safe, requires = ["souvenir_type"])]
+#[opname(>)]
+#[commutator(<)]
+#[negator(<=)]
+#[restrict(scalargtsel)]
+#[join(scalargtjoinsel)]
+fn souvenir_gt(left: Souvenir, right: Souvenir) -> bool {
+    left > right
+}
+
+#[pg_operator(immutable, parallel_safe, requires = ["souvenir_type"])]
+#[opname(>=)]
+#[commutator(<=)]
+#[negator(<)]
+#[restrict(scalargesel)]
+#[join(scalargejoinsel)]
+fn souvenir_ge(left: Souvenir, right: Souvenir) -> bool {
+    left >= right
+}
+
+/// Compare two identifiers by their bytes, for btree indexes.
+#[pg_extern(immutable, strict, parallel_safe, requires = ["souvenir_type"])]
+fn souvenir_cmp(left: Souvenir, right: Souvenir) -> i32 {
+    left.cmp(&right) as i32
+}
+
+/// Hash the bytes of an identifier, for hash indexes.
+#[pg_extern(immutable, strict, parallel_safe, requires = ["souvenir_type"])]
+fn souvenir_hash(id: Souvenir) -> i32 {
+    pgrx::misc::pgrx_seahash(&id.0) as i32
+}
+
+extension_sql!(
+    r#"
+CREATE OPERATOR CLASS souvenir_btree_ops DEFAULT FOR TYPE souvenir USING btree AS
+    OPERATOR 1 <,
+    OPERATOR 2 <=,
+    OPERATOR 3 =,
+    OPERATOR 4 >=,
+    OPERATOR 5 >,
+    FUNCTION 1 souvenir_cmp(souvenir, souvenir);
+
+CREATE OPERATOR CLASS souvenir_hash_ops DEFAULT FOR TYPE souvenir USING hash AS
+    OPERATOR 1 =,
+    FUNCTION 1 souvenir_hash(souvenir);
+"#,
+    name = "souvenir_opclasses",
+    requires = [
+        souvenir_eq,
+        souvenir_lt,
+        souvenir_le,
+        souvenir_gt,
+        souvenir_ge,
+        souvenir_cmp,
+        souvenir_hash
+    ]
+);
+
+/// Get the prefix of an identifier.
+#[pg_extern(immutable, parallel_safe, requires = ["souvenir_type"])]
+fn souvenir_prefix(id: Souvenir) -> String {
+    id.to_id().prefix().to_string()
+}
+
+/// Convert an identifier into a `uuid` with the same bytes.
+#[pg_extern(immutable, parallel_safe, requires = ["souvenir_type"])]
+fn souvenir_to_uuid(id: Souvenir) -> Uuid {
+    Uuid::from_bytes(id.0)
+}
+
+/// Convert a `uuid` into an identifier with the same bytes. If the top 20
+/// bits of the `uuid` are not a valid prefix, this will error.
+#[pg_extern(immutable, parallel_safe, requires = ["souvenir_type"])]
+fn uuid_to_souvenir(uuid: Uuid) -> Souvenir {
+    Id::from_bytes(*uuid.as_bytes())
+        .map(Souvenir::from)
+        .unwrap_or_else(|error| raise(PgSqlErrorCode::ERRCODE_INVALID_PARAMETER_VALUE, error))
+}
+
+extension_sql!(
+    r#"
+CREATE CAST (souvenir AS uuid) WITH FUNCTION souvenir_to_uuid(souvenir) AS ASSIGNMENT;
+CREATE CAST (uuid AS souvenir) WITH FUNCTION uuid_to_souvenir(uuid) AS ASSIGNMENT;
+"#,
+    name = "souvenir_casts",
+    requires = [souvenir_to_uuid, uuid_to_souvenir]
+);
+
+#[cfg(any(test, feature = "pg_test"))]
+#[pg_schema]
+mod tests {
+    use pgrx::prelude::*;
+    use souvenir_core::id::Id;
+
+    const ID: &str = "user_02v58c5a3fy30k560qrtg4";
+    const UUID: &str = "accb202d-950c-2a86-ff0c-1329817c6a04";
+
+    #[pg_test]
+    fn text() -> Result<(), spi::Error> {
+        let value = Spi::get_one::<String>(&format!("SELECT '{ID}'::souvenir::text"))?;
+        assert_eq!(Some(ID), value.as_deref());
+
+        Ok(())
+    }
+
+    #[pg_test]
+    #[should_panic(expected = "invalid input syntax for type souvenir")]
+    fn text_invalid() {
+        Spi::run("SELECT 'User_02v58c5a3fy30k560qrtg4'::souvenir").unwrap();
+    }
+
+    #[pg_test]
+    fn prefix() -> Result<(), spi::Error> {
+        let value = Spi::get_one::<String>(&format!("SELECT souvenir_prefix('{ID}')"))?;
+        assert_eq!(Some("user"), value.as_deref());
+
+        Ok(())
+    }
+
+    #[pg_test]
+    fn uuid() -> Result<(), spi::Error> {
+        let value = Spi::get_one::<String>(&format!("SELECT '{ID}'::souvenir::uuid::text"))?;
+        assert_eq!(Some(UUID), value.as_deref());
+
+        let value = Spi::get_one::<String>(&format!("SELECT '{UUID}'::uuid::souvenir::text"))?;
+        assert_eq!(Some(ID), value.as_deref());
+
+        Ok(())
+    }
+
+    #[pg_test]
+    fn storage() -> Result<(), spi::Error> {
+        let value = Spi::get_one::<i32>(&format!("SELECT pg_column_size('{ID}'::souvenir)"))?;
+        assert_eq!(Some(16), value);
+
+        let value = Spi::get_one::<Vec<u8>>(&format!("SELECT souvenir_send('{ID}')"))?;
+        assert_eq!(Some(Id::parse(ID).unwrap().to_bytes().to_vec()), value);
+
+        Ok(())
+    }
+
+    #[pg_test]
+    fn index() -> Result<(), spi::Error> {
+        Spi::run("CREATE TABLE ids (id souvenir PRIMARY KEY)")?;
+        Spi::run("CREATE INDEX ids_hash ON ids USING hash (id)")?;
+        Spi::run(&format!(
+            "INSERT INTO ids VALUES ('{ID}'), ('org_02v58c5a3fy30k560qrtg4')"
+        ))?;
+
+        let value = Spi::get_one::<String>("SELECT id::text FROM ids ORDER BY id LIMIT 1")?;
+        assert_eq!(Some("org_02v58c5a3fy30k560qrtg4"), value.as_deref());
+
+        let value = Spi::get_one::<i64>(&format!("SELECT count(*) FROM ids WHERE id = '{ID}'"))?;
+        assert_eq!(Some(1), value);
+
+        Ok(())
+    }
+}
+
+/// This module is required by `cargo pgrx test` invocations.
+#[cfg(test)]
+pub mod pg_test {
+    pub fn setup(_options: Vec<&str>) {}
+
+    #[must_use]
+    pub fn postgresql_conf_options() -> Vec<&'static str> {
+        vec![]
+    }
+}
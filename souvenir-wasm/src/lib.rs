@@ -4,18 +4,24 @@ use wasm_bindgen::prelude::*;
 type Value = souvenir::Id;
 
 fn convert_error(err: Error) -> JsError {
-    let message = match err {
-        Error::InvalidData => "InvalidData: The provided data is not a valid Id",
-        Error::InvalidPrefix => "InvalidPrefix: The provided prefix is not valid",
-        Error::InvalidChar { found: _ } => "InvalidChar: The input contains and invalid character",
-        Error::InvalidFormat => "InvalidFormat: The input is not in the correct format",
-        Error::InvalidLength {
-            expected: _,
-            found: _,
-        } => "InvalidLength: The input has the incorrect length",
+    JsError::new(&error_message(&err))
+}
+
+fn error_message(err: &Error) -> String {
+    let kind = match err {
+        Error::InvalidData => "InvalidData",
+        Error::InvalidPrefix { .. } => "InvalidPrefix",
+        Error::InvalidChar { .. } => "InvalidChar",
+        Error::InvalidFormat { .. } => "InvalidFormat",
+        Error::InvalidLength { .. } => "InvalidLength",
+        Error::PrefixMismatch { .. } => "PrefixMismatch",
+        Error::UnknownPrefix { .. } => "UnknownPrefix",
+        Error::Overflow => "Overflow",
+        Error::ChecksumMismatch { .. } => "ChecksumMismatch",
+        _ => "Error",
     };
 
-    JsError::new(message)
+    format!("{kind}: {err}")
 }
 
 /// A 128-bit prefixed identifier
@@ -28,15 +34,7 @@ impl Id {
     /// Create an `Id` from an array of bytes
     #[wasm_bindgen(constructor)]
     pub fn new(value: &[u8]) -> Result<Self, JsError> {
-        value
-            .try_into()
-            .map_err(|_| Error::InvalidLength {
-                expected: 16,
-                found: value.len(),
-            })
-            .and_then(Value::from_bytes)
-            .map(Self)
-            .map_err(convert_error)
+        Value::try_from(value).map(Self).map_err(convert_error)
     }
 
     /// Convert this `Id` to an array of bytes
@@ -100,3 +98,17 @@ impl Id {
         Value::test(value)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::{Value, error_message};
+
+    #[test]
+    fn invalid_length() {
+        let error = Value::try_from(&[0; 3][..]).unwrap_err();
+        assert_eq!(
+            "InvalidData: input contains invalid data",
+            error_message(&error)
+        );
+    }
+}
@@ -1,4 +1,5 @@
 #![forbid(unsafe_code)]
+#![cfg_attr(not(feature = "std"), no_std)]
 
 //! # souvenir
 //!
@@ -27,22 +28,69 @@
 //! Integrations for various libraries and databases are also (optionally)
 //! available:
 //! - (De)serialization with [`serde`](https://docs.rs/serde/latest/serde/)
+//! - MongoDB binary storage with [`bson`](https://docs.rs/bson/latest/bson/)
+//! - Fixed 16-byte binary encoding with
+//!   [`bincode`](https://docs.rs/bincode/latest/bincode/),
+//!   [`borsh`](https://docs.rs/borsh/latest/borsh/) and
+//!   [`rkyv`](https://docs.rs/rkyv/latest/rkyv/), including validated
+//!   zero-copy access
+//! - Validated casts from raw buffers with
+//!   [`bytemuck`](https://docs.rs/bytemuck/latest/bytemuck/) and
+//!   [`zerocopy`](https://docs.rs/zerocopy/latest/zerocopy/)
 //! - Random ID generation with [`rand`](https://docs.rs/rand/latest/rand/)
+//! - Conversions to and from [`uuid`](https://docs.rs/uuid/latest/uuid/)
+//! - Path and query extractors for
+//!   [`axum`](https://docs.rs/axum/latest/axum/) and
+//!   [`actix-web`](https://docs.rs/actix-web/latest/actix_web/)
+//! - JSON Schema and OpenAPI schemas with
+//!   [`schemars`](https://docs.rs/schemars/latest/schemars/) and
+//!   [`utoipa`](https://docs.rs/utoipa/latest/utoipa/)
 //! - Postgres, MySQL, and Sqlite support with
 //!   [`sqlx`](https://docs.rs/sqlx/latest/sqlx/) and
 //!   [`diesel`](https://docs.rs/diesel/latest/diesel/)
+//! - Postgres support with
+//!   [`postgres-types`](https://docs.rs/postgres-types/latest/postgres_types/),
+//!   for `tokio-postgres` and `postgres`, and Sqlite support with
+//!   [`rusqlite`](https://docs.rs/rusqlite/latest/rusqlite/)
+//! - Entity fields and primary keys with
+//!   [`sea-orm`](https://docs.rs/sea-orm/latest/sea_orm/)
 
 pub use souvenir_core::{
-    encoding::ALPHABET, error::*, id::*, identifiable::*, prefix::*, suffix::*, tagged::*,
+    clock::*, encoding::ALPHABET, error::*, id::*, identifiable::*, long::*, options::*, prefix::*,
+    string::*, suffix::*, tagged::*, typed::*,
 };
 
+#[cfg(feature = "alloc")]
+pub use souvenir_core::registry::*;
+
+#[cfg(feature = "serde")]
+pub use souvenir_core::serde;
+
+#[cfg(any(
+    all(feature = "sqlite", any(feature = "diesel", feature = "sqlx")),
+    feature = "rusqlite"
+))]
+pub use souvenir_core::blob::*;
+
+#[cfg(feature = "sea-orm")]
+pub use souvenir_core::text::*;
+
+#[cfg(any(feature = "actix-web", feature = "axum"))]
+pub use souvenir_core::extract::*;
+
+#[cfg(all(feature = "rand", feature = "std"))]
+pub use souvenir_core::generator::*;
+
 #[cfg(feature = "macros")]
 pub use souvenir_macros::*;
 
 /// Re-exports of the most common imports.
 pub mod prelude {
-    pub use crate::{Id, Identifiable, Prefix, Suffix, Tagged};
+    pub use crate::{Id, Identifiable, Prefix, Suffix, Tagged, TypedId};
+
+    #[cfg(all(feature = "rand", feature = "std"))]
+    pub use crate::IdGenerator;
 
     #[cfg(feature = "macros")]
-    pub use crate::{id, prefix};
+    pub use crate::{generator, id, prefix};
 }